@@ -0,0 +1,85 @@
+//! Throughput of the rendering, in elements per second, for representative deploys.
+//!
+//! ```bash
+//! cargo bench --bench parsing
+//! ```
+
+use casper_deploy_generator::{deploy_to_elements, Options};
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, AccessRights, RuntimeArgs, SecretKey, TimeDiff, Timestamp,
+    URef, U512,
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+fn system_payment() -> ExecutableDeployItem {
+    ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: runtime_args! { "amount" => U512::from(1_000_000_000u64) },
+    }
+}
+
+fn make_deploy(session: ExecutableDeployItem) -> Deploy {
+    let secret_key = SecretKey::ed25519_from_bytes([1u8; 32]).unwrap();
+    Deploy::new(
+        Timestamp::from(1_620_138_035_104),
+        TimeDiff::from_seconds(60 * 60),
+        1,
+        vec![],
+        "mainnet".to_string(),
+        system_payment(),
+        session,
+        &secret_key,
+        None,
+    )
+}
+
+fn small_transfer() -> Deploy {
+    make_deploy(ExecutableDeployItem::Transfer {
+        args: runtime_args! {
+            "amount" => U512::from(100_000_000u64),
+            "target" => URef::new([1u8; 32], AccessRights::READ),
+            "id" => 1u64,
+        },
+    })
+}
+
+fn contract_call_with_many_args() -> Deploy {
+    let mut args = RuntimeArgs::new();
+    for idx in 0..100u64 {
+        args.insert(format!("arg_{}", idx), idx).unwrap();
+    }
+    make_deploy(ExecutableDeployItem::StoredContractByName {
+        name: "contract".to_string(),
+        entry_point: "call".to_string(),
+        args,
+    })
+}
+
+fn large_module_bytes() -> Deploy {
+    make_deploy(ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::from(vec![0xab; 500 * 1024]),
+        args: runtime_args! { "amount" => U512::from(100_000_000u64) },
+    })
+}
+
+fn parsing(c: &mut Criterion) {
+    let options = Options::default().expert(true);
+    let mut group = c.benchmark_group("deploy_to_elements");
+    for (name, deploy) in [
+        ("small_transfer", small_transfer()),
+        ("contract_call_100_args", contract_call_with_many_args()),
+        ("module_bytes_500kb", large_module_bytes()),
+    ] {
+        let element_count = deploy_to_elements(&deploy, &options).unwrap().len();
+        group.throughput(Throughput::Elements(element_count as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| deploy_to_elements(&deploy, &options).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parsing);
+criterion_main!(benches);
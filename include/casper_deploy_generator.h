@@ -0,0 +1,34 @@
+#ifndef CASPER_DEPLOY_GENERATOR_H
+#define CASPER_DEPLOY_GENERATOR_H
+
+/* Generated with cbindgen, do not edit. Regenerate with `make ffi-header`. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Returns the elements the Ledger displays for the serialized deploy,
+ * as a NUL-terminated JSON array of `{ "label", "value", "expert" }` objects.
+ *
+ * Returns NULL when the bytes aren't a valid deploy or it can't be rendered.
+ * The returned string must be released with `casper_free_elements`.
+ *
+ * # Safety
+ *
+ * `blob` must point to `len` readable bytes.
+ */
+char *casper_deploy_to_elements(const uint8_t *blob, uintptr_t len, bool expert);
+
+/**
+ * Releases the string returned by `casper_deploy_to_elements`.
+ *
+ * # Safety
+ *
+ * `elements` must be NULL or a pointer returned by `casper_deploy_to_elements`,
+ * not released before.
+ */
+void casper_free_elements(char *elements);
+
+#endif /* CASPER_DEPLOY_GENERATOR_H */
@@ -0,0 +1,227 @@
+//! Entry point ABIs supplied by the users, so that calls of arbitrary contracts
+//! are displayed with their arguments decoded and labelled – rather than their digest.
+//!
+//! ```toml
+//! [[entry_point]]
+//! name = "swap"
+//! # Optional: only the calls of this contract (or package, for versioned calls).
+//! contract = "hash-8a3b0c9d..."
+//!
+//! # Arguments of the entry point, in the order of the call.
+//! [[entry_point.args]]
+//! name = "amount_in"
+//! type = "U256"
+//! label = "Amount in"
+//! # Display hints: the value is scaled by 10^decimals and followed by the unit.
+//! decimals = 9
+//! unit = "WCSPR"
+//! ```
+
+use std::convert::TryFrom;
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::CLType;
+use serde::Deserialize;
+use thousands::Separable;
+
+use crate::{
+    ledger::{is_printable, Element, TxnPhase, LEDGER_VIEW_NAME_CHAR_COUNT},
+    parser::deploy::{deploy_type, stored_contract_call},
+    recognizer::DeployRecognizer,
+    registry::{called_contract, normalized_hash},
+    utils::cl_value_to_string,
+};
+
+/// Entry points described by the users, read from a TOML or JSON file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "AbiFile")]
+pub struct Abi {
+    entry_points: Vec<EntryPointAbi>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AbiFile {
+    #[serde(default, rename = "entry_point")]
+    entry_points: Vec<EntryPointAbi>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EntryPointAbi {
+    name: String,
+    /// Hex-encoded hash of the contract, if the entry point is specific to it.
+    #[serde(default)]
+    contract: Option<String>,
+    #[serde(default)]
+    args: Vec<ArgAbi>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ArgAbi {
+    name: String,
+    #[serde(rename = "type")]
+    cl_type: CLType,
+    label: String,
+    /// Number of decimals of the unsigned integer amounts.
+    #[serde(default)]
+    decimals: Option<u32>,
+    /// Unit displayed after the value.
+    #[serde(default)]
+    unit: Option<String>,
+    /// Whether to display the argument in expert mode only.
+    #[serde(default)]
+    expert: bool,
+}
+
+impl TryFrom<AbiFile> for Abi {
+    type Error = String;
+
+    fn try_from(file: AbiFile) -> Result<Self, Self::Error> {
+        let mut entry_points = vec![];
+        for mut entry_point in file.entry_points {
+            if let Some(contract) = &entry_point.contract {
+                entry_point.contract = Some(normalized_hash(contract)?);
+            }
+            for arg in &entry_point.args {
+                if arg.label.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT
+                    || !is_printable(&arg.label)
+                {
+                    return Err(format!(
+                        "label {} of {} isn't printable in {} characters",
+                        arg.label, entry_point.name, LEDGER_VIEW_NAME_CHAR_COUNT
+                    ));
+                }
+                if arg.decimals.is_some() && !is_unsigned(&arg.cl_type) {
+                    return Err(format!(
+                        "decimals of {} in {} need an unsigned integer type",
+                        arg.name, entry_point.name
+                    ));
+                }
+            }
+            entry_points.push(entry_point);
+        }
+        Ok(Abi { entry_points })
+    }
+}
+
+impl Abi {
+    /// Reads the ABI from JSON, of the same structure as the TOML file.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads the ABI from the TOML (or, with the `.json` extension, JSON) file.
+    #[cfg(feature = "cli")]
+    pub(crate) fn read(path: &std::path::Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        if path.extension().map_or(false, |ext| ext == "json") {
+            Self::from_json(&content).map_err(|err| err.to_string())
+        } else {
+            toml::from_str(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+}
+
+impl DeployRecognizer for Abi {
+    /// Decodes calls with exactly the arguments of a described entry point, in its order.
+    ///
+    /// Values that can't be decoded leave the call to the built-in rendering.
+    fn recognize(&self, item: &ExecutableDeployItem) -> Option<Vec<Element>> {
+        let (entry_point, args) = stored_contract_call(item)?;
+        let contract = called_contract(item).map(hex::encode);
+        let abi = self.entry_points.iter().find(|abi| {
+            abi.name == entry_point
+                && (abi.contract.is_none() || abi.contract == contract)
+                && abi.args.len() == args.len()
+                && abi.args.iter().zip(args.named_args()).all(|(arg, named)| {
+                    arg.name == named.name() && &arg.cl_type == named.cl_value().cl_type()
+                })
+        })?;
+        // Recognizers aren't told the phase, and stored contracts are hardly ever payments.
+        let mut elements = deploy_type(TxnPhase::Session, item);
+        elements.push(Element::regular("Entry-point", entry_point.to_string()));
+        for (arg, named) in abi.args.iter().zip(args.named_args()) {
+            let mut value = cl_value_to_string(named.cl_value()).ok()?;
+            if let Some(decimals) = arg.decimals {
+                value = scaled(&value, decimals);
+            }
+            if let Some(unit) = &arg.unit {
+                value = format!("{} {}", value, unit);
+            }
+            let mut element = Element::regular(arg.label.clone(), value);
+            if arg.expert {
+                element.as_expert();
+            }
+            elements.push(element);
+        }
+        Some(elements)
+    }
+}
+
+fn is_unsigned(cl_type: &CLType) -> bool {
+    matches!(
+        cl_type,
+        CLType::U8 | CLType::U32 | CLType::U64 | CLType::U128 | CLType::U256 | CLType::U512
+    )
+}
+
+// Places the decimal point in the `digits` of an integer, dropping the trailing zeros.
+pub(crate) fn scaled(digits: &str, decimals: u32) -> String {
+    let decimals = decimals as usize;
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (integer, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.separate_with_spaces()
+    } else {
+        format!("{}.{}", integer.separate_with_spaces(), fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256};
+
+    use super::{scaled, Abi};
+    use crate::recognizer::DeployRecognizer;
+
+    #[test]
+    fn amounts_are_scaled_by_decimals() {
+        assert_eq!(scaled("1500000000", 9), "1.5");
+        assert_eq!(scaled("15", 9), "0.000000015");
+        assert_eq!(scaled("2000000000000", 9), "2 000");
+        assert_eq!(scaled("7", 0), "7");
+    }
+
+    #[test]
+    fn only_calls_with_the_described_arguments_are_decoded() {
+        let abi = Abi::from_json(
+            r#"{"entry_point": [{
+                "name": "swap",
+                "args": [{ "name": "amount_in", "type": "U256", "label": "Amount in",
+                           "decimals": 9, "unit": "WCSPR" }]
+            }]}"#,
+        )
+        .unwrap();
+        let call = |args: RuntimeArgs| ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([1u8; 32]),
+            entry_point: "swap".to_string(),
+            args,
+        };
+        let elements = abi
+            .recognize(&call(
+                runtime_args! { "amount_in" => U256::from(2_500_000_000u64) },
+            ))
+            .unwrap();
+        assert_eq!(elements.last().unwrap().label(), "Amount in");
+        assert_eq!(elements.last().unwrap().value(), "2.5 WCSPR");
+        assert!(abi
+            .recognize(&call(runtime_args! { "amount_in" => 1u64 }))
+            .is_none());
+    }
+}
@@ -0,0 +1,90 @@
+//! Aliases of the labels of the elements, so that wording changes requested by wallet UX teams
+//! are made in a single file – and vectors with the old wording can still be generated.
+//!
+//! ```toml
+//! [labels]
+//! "Cntrct hash" = "Contract"
+//! "Deps #" = "Deps count"
+//! ```
+
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use serde::Deserialize;
+
+use crate::ledger::{is_printable, Element, LEDGER_VIEW_NAME_CHAR_COUNT};
+
+/// Labels displayed instead of the built-in ones, read from a TOML or JSON file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "AliasesFile")]
+pub struct LabelAliases {
+    /// Aliases by the built-in label.
+    labels: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AliasesFile {
+    #[serde(default)]
+    labels: BTreeMap<String, String>,
+}
+
+impl TryFrom<AliasesFile> for LabelAliases {
+    type Error = String;
+
+    fn try_from(file: AliasesFile) -> Result<Self, Self::Error> {
+        for (label, alias) in &file.labels {
+            if alias.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT || !is_printable(alias) {
+                return Err(format!(
+                    "alias {} of {} isn't printable in {} characters",
+                    alias, label, LEDGER_VIEW_NAME_CHAR_COUNT
+                ));
+            }
+        }
+        Ok(LabelAliases {
+            labels: file.labels,
+        })
+    }
+}
+
+impl LabelAliases {
+    /// Reads the aliases from JSON, of the same structure as the TOML file.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads the aliases from the TOML (or, with the `.json` extension, JSON) file.
+    #[cfg(feature = "cli")]
+    pub(crate) fn read(path: &std::path::Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        if path.extension().map_or(false, |ext| ext == "json") {
+            Self::from_json(&content).map_err(|err| err.to_string())
+        } else {
+            toml::from_str(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    /// Relabels the element with the alias of its label, if there's one.
+    pub(crate) fn apply(&self, element: Element) -> Element {
+        match self.labels.get(element.label()) {
+            Some(alias) => element.relabelled(alias.clone()),
+            None => element,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LabelAliases;
+    use crate::ledger::Element;
+
+    #[test]
+    fn aliases_must_fit_the_display() {
+        let aliases =
+            LabelAliases::from_json(r#"{"labels": {"Cntrct hash": "Contract"}}"#).unwrap();
+        let element = aliases.apply(Element::regular("Cntrct hash", "00".to_string()));
+        assert_eq!(element.label(), "Contract");
+        assert!(LabelAliases::from_json(r#"{"labels": {"Deps #": "Dependencies"}}"#).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! Framing of the sign requests sent to the Casper Ledger app.
+
+/// Class of the Casper app's instructions.
+pub const CLA: u8 = 0x11;
+/// Instruction signing a deploy.
+pub const INS_SIGN: u8 = 0x02;
+/// Maximum number of the deploy's bytes sent in a single APDU.
+pub const CHUNK_SIZE: usize = 250;
+/// Derivation path of the signing account, `m/44'/506'/0'/0/0`.
+pub const DEFAULT_PATH: [u32; 5] = [0x8000_002c, 0x8000_01fa, 0x8000_0000, 0, 0];
+
+// Kinds of the chunks, passed in P1.
+const P1_INIT: u8 = 0x00;
+const P1_ADD: u8 = 0x01;
+const P1_LAST: u8 = 0x02;
+
+/// Returns APDUs of the request signing the `blob` with the key at the derivation `path`.
+///
+/// The first APDU carries the path, followed by the blob split into chunks of [`CHUNK_SIZE`] bytes.
+/// The app displays the deploy and waits for the user's approval after the last one.
+pub fn sign_apdus(path: &[u32], blob: &[u8]) -> Vec<Vec<u8>> {
+    let path_bytes: Vec<u8> = path.iter().flat_map(|index| index.to_le_bytes()).collect();
+    let mut apdus = vec![apdu(P1_INIT, &path_bytes)];
+
+    let chunk_count = (blob.len() + CHUNK_SIZE - 1) / CHUNK_SIZE;
+    for (idx, chunk) in blob.chunks(CHUNK_SIZE).enumerate() {
+        let p1 = if idx + 1 == chunk_count {
+            P1_LAST
+        } else {
+            P1_ADD
+        };
+        apdus.push(apdu(p1, chunk));
+    }
+    apdus
+}
+
+fn apdu(p1: u8, data: &[u8]) -> Vec<u8> {
+    let mut apdu = vec![CLA, INS_SIGN, p1, 0x00, data.len() as u8];
+    apdu.extend_from_slice(data);
+    apdu
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_apdus, CHUNK_SIZE, DEFAULT_PATH};
+
+    #[test]
+    fn splits_blob_into_chunks() {
+        let blob = vec![7u8; CHUNK_SIZE + 1];
+        let apdus = sign_apdus(&DEFAULT_PATH, &blob);
+        assert_eq!(apdus.len(), 3);
+        assert_eq!(&apdus[0][..5], &[0x11, 0x02, 0x00, 0x00, 20]);
+        assert_eq!(&apdus[0][5..9], &[0x2c, 0x00, 0x00, 0x80]);
+        assert_eq!(&apdus[1][..5], &[0x11, 0x02, 0x01, 0x00, 250]);
+        assert_eq!(apdus[2], vec![0x11, 0x02, 0x02, 0x00, 1, 7]);
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    convert::TryFrom,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use casper_node::types::Deploy;
+use clap::{Parser, Subcommand};
+use serde::Deserialize;
+
+use crate::{
+    config::GeneratorConfig,
+    ledger::DEFAULT_PAGE_LIMIT,
+    output::{dir::DirMode, OutputFormat},
+    parser::DeployKind,
+};
+
+/// Category of the generated samples, used for regenerating only a subset of the vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub(crate) enum SampleCategory {
+    /// Native token transfers.
+    Transfers,
+    /// Delegate, undelegate and redelegate calls.
+    Auction,
+    /// Calls of CEP-18 token and CEP-78 NFT contracts.
+    Tokens,
+    /// Calls of key management, the faucet and any other contract call.
+    Contracts,
+    /// All legacy deploys.
+    Legacy,
+}
+
+impl SampleCategory {
+    /// Returns `true` when the deploy belongs to the category.
+    pub(crate) fn matches(&self, deploy: &Deploy) -> bool {
+        let kind = DeployKind::of(deploy.session());
+        match self {
+            SampleCategory::Transfers => kind == DeployKind::Transfer,
+            SampleCategory::Auction => kind.is_auction(),
+            SampleCategory::Tokens => kind.is_token(),
+            SampleCategory::Contracts => matches!(
+                kind,
+                DeployKind::ContractExecution | DeployKind::AccountMgmt | DeployKind::Faucet
+            ),
+            SampleCategory::Legacy => true,
+        }
+    }
+}
+
+impl FromStr for SampleCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "transfers" => Ok(SampleCategory::Transfers),
+            "auction" => Ok(SampleCategory::Auction),
+            "tokens" => Ok(SampleCategory::Tokens),
+            "contracts" => Ok(SampleCategory::Contracts),
+            "legacy" => Ok(SampleCategory::Legacy),
+            "v1" => Err("TransactionV1 samples are not supported by this generator".to_string()),
+            other => Err(format!("unknown sample category: {}", other)),
+        }
+    }
+}
+
+impl TryFrom<String> for SampleCategory {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Generator of the Ledger test vectors for the Casper app.
+#[derive(Debug, Parser)]
+#[command(version)]
+pub(crate) struct Cli {
+    #[command(subcommand)]
+    pub(crate) command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum Command {
+    /// Generates the test vectors.
+    Generate(GenerateArgs),
+    /// Prints what the Ledger displays for a single deploy.
+    Parse(ParseArgs),
+    /// Compares two sets of test vectors.
+    Diff(DiffArgs),
+    /// Checks that a set of test vectors is well-formed.
+    Validate(ValidateArgs),
+    /// Prints what the Ledger displays for every deploy accepted by a node, as they come.
+    Watch(WatchArgs),
+    /// Writes the test vectors into a checkout of the Ledger app's repository.
+    Sync(SyncArgs),
+    /// Measures how fast the deploys of a set of test vectors are rendered.
+    Bench(BenchArgs),
+    /// Appends approvals signed elsewhere to a sample of the test vectors.
+    Attach(AttachArgs),
+}
+
+/// Arguments of the `generate` command.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub(crate) struct GenerateArgs {
+    /// Configuration file with the default values of the options.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) config: Option<PathBuf>,
+    /// Category of samples to generate, can be repeated. The whole corpus when missing.
+    #[arg(long, value_name = "CATEGORY")]
+    pub(crate) only: Vec<SampleCategory>,
+    /// Path of the manifest file to write, if requested.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) index: Option<PathBuf>,
+    /// Directory with captured deploys to include in the generated vectors.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) import_dir: Option<PathBuf>,
+    /// Directory to write the fuzzing corpus to, if requested.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) fuzz_corpus: Option<PathBuf>,
+    /// Format of the generated test vectors [default: json].
+    #[arg(long)]
+    pub(crate) format: Option<OutputFormat>,
+    /// Number of pages after which the Ledger suggests switching to the expert mode.
+    #[arg(long, value_name = "COUNT")]
+    pub(crate) page_limit: Option<u8>,
+    /// Number of pages above which values are truncated, with the full value
+    /// moved to an expert element.
+    #[arg(long, value_name = "COUNT", value_parser = clap::value_parser!(u8).range(1..))]
+    pub(crate) element_page_limit: Option<u8>,
+    /// Size of module bytes above which a deploy's vector describes them by their length and hash
+    /// only, without a blob.
+    #[arg(long, value_name = "BYTES")]
+    pub(crate) max_module_bytes: Option<usize>,
+    /// Write the deploys the device can't display, and the samples marked as such,
+    /// as vectors of blind signing, displaying their hash only – instead of skipping them.
+    #[arg(long)]
+    pub(crate) blind_signing: bool,
+    /// TOML (or JSON) file with the aliases of the labels, displayed instead of them.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) label_aliases: Option<PathBuf>,
+    /// Amount of motes above which payments and transfers are warned about.
+    #[arg(long, value_name = "MOTES")]
+    pub(crate) warn_above: Option<u64>,
+    /// TOML (or JSON) file with the currencies of the amounts, by the name of the chain.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) currencies: Option<PathBuf>,
+    /// Offset of the time zone the timestamps are displayed in too, like `+02:00`.
+    #[arg(long, value_name = "OFFSET", value_parser = parse_timezone_offset, allow_hyphen_values = true)]
+    pub(crate) timezone_offset: Option<i32>,
+    /// Record the sections of the deploy the pages belong to, for grouping them.
+    #[arg(long)]
+    pub(crate) sections: bool,
+    /// Path to write the test vectors to. Standard output when missing or `-`.
+    #[arg(long, value_name = "PATH", conflicts_with = "out_dir")]
+    pub(crate) out: Option<PathBuf>,
+    /// Directory to write the Zemu snapshots to, if requested.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) zemu_dir: Option<PathBuf>,
+    /// Directory to write the raw blobs with their expected pages to, if requested.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) blob_dir: Option<PathBuf>,
+    /// Directory to write the APDU transcripts of the sign requests to, if requested.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) apdu_dir: Option<PathBuf>,
+    /// Directory to write the whole vector set to, if requested.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) out_dir: Option<PathBuf>,
+    /// Replaces the whole content of the `--out-dir`.
+    #[arg(long, requires = "out_dir", conflicts_with = "merge")]
+    pub(crate) clean: bool,
+    /// Overwrites only the regenerated files of the `--out-dir`.
+    #[arg(long, requires = "out_dir")]
+    pub(crate) merge: bool,
+    #[command(flatten)]
+    pub(crate) modes: ModeArgs,
+    /// Seed of the samples' randomness, `CL_TEST_SEED` (or a random one) when missing.
+    #[arg(skip)]
+    pub(crate) seed: Option<[u8; 16]>,
+}
+
+/// Display modes of the Ledger to emit the elements of.
+#[derive(Debug, Clone, Copy, Default, clap::Args)]
+pub(crate) struct ModeArgs {
+    /// Emits only the elements displayed in regular mode.
+    #[arg(long, conflicts_with = "expert")]
+    pub(crate) regular_only: bool,
+    /// Emits only the elements displayed in expert mode.
+    #[arg(long)]
+    pub(crate) expert: bool,
+}
+
+impl ModeArgs {
+    pub(crate) fn includes_regular(&self) -> bool {
+        !self.expert
+    }
+
+    pub(crate) fn includes_expert(&self) -> bool {
+        !self.regular_only
+    }
+}
+
+impl GenerateArgs {
+    /// Fills in the options missing from the command line with the ones from the config file.
+    pub(crate) fn merged_with(mut self, config: GeneratorConfig) -> Result<Self, String> {
+        let output = config.output;
+        if self.only.is_empty() {
+            self.only = output.only;
+        }
+        self.format = self.format.or(output.format);
+        self.page_limit = self.page_limit.or(config.device.page_limit);
+        self.element_page_limit = self.element_page_limit.or(config.device.element_page_limit);
+        self.max_module_bytes = self.max_module_bytes.or(output.max_module_bytes);
+        self.out = self.out.or(output.out);
+        self.out_dir = self.out_dir.or(output.out_dir);
+        self.index = self.index.or(output.index);
+        self.import_dir = self.import_dir.or(output.import_dir);
+        self.fuzz_corpus = self.fuzz_corpus.or(output.fuzz_corpus);
+        self.zemu_dir = self.zemu_dir.or(output.zemu_dir);
+        self.blob_dir = self.blob_dir.or(output.blob_dir);
+        self.apdu_dir = self.apdu_dir.or(output.apdu_dir);
+        self.blind_signing |= output.blind_signing;
+        self.sections |= output.sections;
+        self.label_aliases = self.label_aliases.or(output.label_aliases);
+        self.warn_above = self.warn_above.or(output.warn_above);
+        self.currencies = self.currencies.or(output.currencies);
+        if self.timezone_offset.is_none() {
+            if let Some(offset) = &output.timezone_offset {
+                self.timezone_offset = Some(parse_timezone_offset(offset)?);
+            }
+        }
+        self.modes.regular_only |= output.regular_only;
+        self.modes.expert |= output.expert;
+
+        // Conflicts within the command line alone are already rejected by the parser.
+        if self.out.is_some() && self.out_dir.is_some() {
+            return Err("`out` can't be used together with `out_dir`".to_string());
+        }
+        if self.modes.regular_only && self.modes.expert {
+            return Err("`regular_only` can't be used together with `expert`".to_string());
+        }
+        Ok(self)
+    }
+
+    pub(crate) fn format(&self) -> OutputFormat {
+        self.format.unwrap_or_default()
+    }
+
+    pub(crate) fn page_limit(&self) -> u8 {
+        self.page_limit.unwrap_or(DEFAULT_PAGE_LIMIT)
+    }
+
+    /// Returns `true` when the deploy should be included in the output.
+    pub(crate) fn includes_deploy(&self, deploy: &Deploy) -> bool {
+        self.only.is_empty() || self.only.iter().any(|category| category.matches(deploy))
+    }
+
+    /// Messages don't belong to any category, so they are included only in the whole corpus.
+    pub(crate) fn includes_messages(&self) -> bool {
+        self.only.is_empty()
+    }
+
+    /// How to treat the existing content of the `out_dir`.
+    pub(crate) fn dir_mode(&self) -> Option<DirMode> {
+        match (self.clean, self.merge) {
+            (true, _) => Some(DirMode::Clean),
+            (_, true) => Some(DirMode::Merge),
+            _ => None,
+        }
+    }
+
+    /// Returns the arguments with the vectors, index, Zemu snapshots, blobs and APDU transcripts
+    /// all written to the `dir`.
+    pub(crate) fn redirected_to(&self, dir: &Path) -> Self {
+        GenerateArgs {
+            out: Some(dir.join(format!("manual.{}", self.format().extension()))),
+            index: Some(dir.join("index.json")),
+            zemu_dir: Some(dir.join("zemu")),
+            blob_dir: Some(dir.join("blobs")),
+            apdu_dir: Some(dir.join("apdus")),
+            ..self.clone()
+        }
+    }
+}
+
+/// Parses the offset of a time zone, like `+02:00` or `-05:30`, into minutes east of UTC.
+fn parse_timezone_offset(offset: &str) -> Result<i32, String> {
+    let invalid = || format!("{} isn't an offset like +02:00", offset);
+    let (sign, hours_minutes) = if let Some(rest) = offset.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = offset.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return Err(invalid());
+    };
+    let (hours, minutes) = hours_minutes.split_once(':').ok_or_else(invalid)?;
+    let digits = |s: &str| -> Result<i32, String> {
+        match s.len() {
+            2 if s.chars().all(|c| c.is_ascii_digit()) => s.parse().map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    };
+    let (hours, minutes) = (digits(hours)?, digits(minutes)?);
+    // Time zones span from UTC-12:00 to UTC+14:00.
+    if hours > 14 || minutes >= 60 {
+        return Err(invalid());
+    }
+    Ok(sign * (hours * 60 + minutes))
+}
+
+/// Arguments of the `parse` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct ParseArgs {
+    /// JSON file with the deploy – a bare one, the output of `casper-client get-deploy`
+    /// (or `get-transaction`) or the `account_put_deploy` (or `account_put_transaction`)
+    /// request. Standard input when `-`.
+    #[arg(required_unless_present = "node_url")]
+    pub(crate) input: Option<PathBuf>,
+    /// Reads the input as hex-encoded serialized deploy, instead of JSON.
+    #[arg(long)]
+    pub(crate) hex: bool,
+    /// Address of the node to fetch the deploy from, instead of reading it from the input.
+    #[arg(long, value_name = "URL", requires = "hash", conflicts_with_all = ["input", "hex"])]
+    pub(crate) node_url: Option<String>,
+    /// Hash of the deploy to fetch from the node.
+    #[arg(long, value_name = "HEX", requires = "node_url")]
+    pub(crate) hash: Option<String>,
+    /// Format of the printed pages.
+    #[arg(long, default_value = "text")]
+    pub(crate) format: OutputFormat,
+    /// TOML (or JSON) file with the known contracts, displayed with their names.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) registry: Option<PathBuf>,
+    /// TOML (or JSON) file with the ABIs of entry points, whose arguments are decoded with it.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) abi: Option<PathBuf>,
+    /// TOML (or JSON) file with the visibility of the arguments of entry points.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) policy: Option<PathBuf>,
+    /// TOML (or JSON) file with the aliases of the labels, displayed instead of them.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) label_aliases: Option<PathBuf>,
+    /// Amount of motes above which payments and transfers are warned about.
+    #[arg(long, value_name = "MOTES")]
+    pub(crate) warn_above: Option<u64>,
+    /// TOML (or JSON) file with the currencies of the amounts, by the name of the chain.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) currencies: Option<PathBuf>,
+    /// Offset of the time zone the timestamps are displayed in too, like `+02:00`.
+    #[arg(long, value_name = "OFFSET", value_parser = parse_timezone_offset, allow_hyphen_values = true)]
+    pub(crate) timezone_offset: Option<i32>,
+    /// Verify the signatures of the approvals, displaying whether each is valid.
+    #[arg(long)]
+    pub(crate) verify_signatures: bool,
+    /// Display the digests of the values instead of them, for logging what was displayed.
+    #[arg(long)]
+    pub(crate) redact: bool,
+    /// Add the debug tier to the expert pages – the serialized lengths of the parts
+    /// of the deploy, which the device never displays.
+    #[arg(long)]
+    pub(crate) debug: bool,
+    /// Record the sections of the deploy the pages belong to, for grouping them.
+    #[arg(long)]
+    pub(crate) sections: bool,
+    #[command(flatten)]
+    pub(crate) modes: ModeArgs,
+}
+
+/// Arguments of the `diff` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct DiffArgs {
+    /// JSON file with the old test vectors.
+    pub(crate) old: PathBuf,
+    /// JSON file with the new test vectors.
+    pub(crate) new: PathBuf,
+    /// Prints the differences as a JSON report.
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+/// Arguments of the `validate` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct ValidateArgs {
+    /// JSON file with the test vectors.
+    pub(crate) vectors: PathBuf,
+    /// Maximum number of pages a single element may span.
+    #[arg(long, value_name = "COUNT", default_value_t = 10)]
+    pub(crate) max_element_pages: usize,
+    /// Maximum number of elements of a single sample, in either mode.
+    #[arg(long, value_name = "COUNT", default_value_t = 64)]
+    pub(crate) max_elements: usize,
+}
+
+/// Arguments of the `watch` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct WatchArgs {
+    /// Address of the node's event stream, like `http://localhost:9999/events/main`.
+    pub(crate) events_url: String,
+}
+
+/// Arguments of the `bench` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct BenchArgs {
+    /// Directory with the JSON files of test vectors.
+    pub(crate) dir: PathBuf,
+    /// Number of times every deploy is rendered.
+    #[arg(long, value_name = "COUNT", default_value_t = 100)]
+    pub(crate) iterations: usize,
+}
+
+/// Arguments of the `attach` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct AttachArgs {
+    /// JSON file with the test vectors.
+    pub(crate) vectors: PathBuf,
+    /// Identifier of the sample to append the approvals to.
+    #[arg(long)]
+    pub(crate) id: String,
+    /// Approval as `<signer>:<signature>`, both hex-encoded with their algorithm tag.
+    /// Can be repeated.
+    #[arg(long = "approval", value_name = "SIGNER:SIGNATURE", required = true)]
+    pub(crate) approvals: Vec<String>,
+    /// Format of the printed sample.
+    #[arg(long, default_value = "json")]
+    pub(crate) format: OutputFormat,
+}
+
+/// Arguments of the `sync` command.
+#[derive(Debug, clap::Args)]
+pub(crate) struct SyncArgs {
+    /// Checkout of the Zondax `ledger-casper` repository.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) ledger_app_dir: PathBuf,
+    /// Directory with captured deploys to include in the generated vectors.
+    #[arg(long, value_name = "DIR")]
+    pub(crate) import_dir: Option<PathBuf>,
+}
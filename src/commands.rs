@@ -0,0 +1,38 @@
+use std::{
+    fs::{self, File},
+    io::{self, Read},
+    path::Path,
+};
+
+use flate2::read::GzDecoder;
+
+use crate::{
+    ledger::{self, ZondaxRepr},
+    output,
+};
+
+pub(crate) mod attach;
+pub(crate) mod bench;
+pub(crate) mod diff;
+pub(crate) mod generate;
+pub(crate) mod parse;
+pub(crate) mod sync;
+pub(crate) mod validate;
+pub(crate) mod watch;
+
+/// Reads test vectors from the JSON file at `path`, decompressing `.gz` files.
+fn read_vectors(path: &Path) -> Result<Vec<ZondaxRepr>, String> {
+    let content =
+        read_file(path).map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+    ledger::parse_vectors(&content).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    if output::is_gzip(path) {
+        let mut content = String::new();
+        GzDecoder::new(File::open(path)?).read_to_string(&mut content)?;
+        Ok(content)
+    } else {
+        fs::read_to_string(path)
+    }
+}
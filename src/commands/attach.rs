@@ -0,0 +1,56 @@
+use casper_node::types::Deploy;
+use serde_json::json;
+
+use crate::{
+    cli::AttachArgs,
+    import,
+    ledger::{self, LimitedLedgerConfig, DEFAULT_PAGE_LIMIT},
+    output::VectorWriter,
+    sample::Sample,
+};
+
+use super::read_vectors;
+
+/// Prints the sample of the test vectors with the approvals appended to its deploy,
+/// rendered again – so that multisig scenarios can be built from signatures made elsewhere.
+pub(crate) fn run(args: &AttachArgs) -> Result<(), String> {
+    let vectors = read_vectors(&args.vectors)?;
+    let vector = vectors
+        .iter()
+        .find(|vector| vector.id() == args.id)
+        .ok_or_else(|| format!("no test vector {} in {}", args.id, args.vectors.display()))?;
+    let deploy = import::deploy_from_hex(vector.blob())
+        .map_err(|err| format!("failed to decode {}: {}", vector.id(), err))?;
+    let deploy = with_approvals(&deploy, &args.approvals)?;
+
+    let sample = Sample::new(vector.name(), deploy, vector.valid_regular());
+    let repr = ledger::deploy_to_json(
+        vector.index(),
+        vector.id().to_string(),
+        &sample,
+        &LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT),
+    )
+    .map_err(|err| format!("failed to render {}: {}", vector.id(), err))?;
+    let mut writer = VectorWriter::open(args.format, None)
+        .map_err(|err| format!("failed to open the output: {}", err))?;
+    writer
+        .push(repr)
+        .and_then(|()| writer.finish())
+        .map_err(|err| format!("failed to write the output: {}", err))
+}
+
+// Appends the approvals, given as `<signer>:<signature>` in the tagged hex of `casper-client`.
+// They're appended through the JSON form, as the deploy can only be signed with a secret key.
+fn with_approvals(deploy: &Deploy, approvals: &[String]) -> Result<Deploy, String> {
+    let mut json = serde_json::to_value(deploy).map_err(|err| err.to_string())?;
+    let json_approvals = json["approvals"]
+        .as_array_mut()
+        .ok_or_else(|| "deploy without approvals".to_string())?;
+    for approval in approvals {
+        let (signer, signature) = approval
+            .split_once(':')
+            .ok_or_else(|| format!("{} isn't of the form <signer>:<signature>", approval))?;
+        json_approvals.push(json!({ "signer": signer, "signature": signature }));
+    }
+    serde_json::from_value(json).map_err(|err| format!("invalid approval: {}", err))
+}
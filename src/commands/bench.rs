@@ -0,0 +1,118 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+
+use casper_node::types::Deploy;
+
+use crate::{cli::BenchArgs, deploy_to_elements, import, Options};
+
+use super::read_vectors;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+// Allocator counting the allocations for the `bench` command, installed with the
+// `alloc-counting` feature only – other builds report the throughput only.
+#[cfg(feature = "alloc-counting")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::Ordering,
+    };
+
+    use super::{ALLOCATED_BYTES, ALLOCATIONS};
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+}
+
+/// Renders every deploy of the vectors in the directory the requested number of times,
+/// and prints the throughput along with the allocations made per deploy.
+pub(crate) fn run(args: &BenchArgs) -> Result<(), String> {
+    let (deploys, vector_count) = read_deploys(&args.dir)?;
+    if deploys.is_empty() {
+        return Err(format!("no deploys in {}", args.dir.display()));
+    }
+    let options = Options::default().expert(true);
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut element_count = 0;
+    for _ in 0..args.iterations {
+        for deploy in &deploys {
+            // Deploys that can't be rendered are measured as well, up to the error.
+            element_count +=
+                deploy_to_elements(deploy, &options).map_or(0, |elements| elements.len());
+        }
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes;
+
+    let rendered_count = (deploys.len() * args.iterations) as f64;
+    println!(
+        "{} deploys (of {} vectors), {} iterations in {:.3}s",
+        deploys.len(),
+        vector_count,
+        args.iterations,
+        elapsed
+    );
+    println!(
+        "throughput: {:.0} deploys/s, {:.0} elements/s",
+        rendered_count / elapsed,
+        element_count as f64 / elapsed
+    );
+    if allocations > 0 {
+        println!(
+            "allocations: {:.1} per deploy, {:.0} bytes per deploy",
+            allocations as f64 / rendered_count,
+            allocated_bytes as f64 / rendered_count
+        );
+    }
+    Ok(())
+}
+
+// Returns the deploys of all the (possibly compressed) vector files in the `dir`, along with the number of vectors.
+// Vectors of messages are left out, as their blobs aren't deploys.
+fn read_deploys(dir: &Path) -> Result<(Vec<Deploy>, usize), String> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|err| format!("failed to read {}: {}", dir.display(), err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path.to_string_lossy();
+            name.ends_with(".json") || name.ends_with(".json.gz")
+        })
+        .collect();
+    paths.sort();
+
+    let mut deploys = vec![];
+    let mut vector_count = 0;
+    for path in paths {
+        let vectors = read_vectors(&path)?;
+        vector_count += vectors.len();
+        deploys.extend(
+            vectors
+                .iter()
+                .filter_map(|vector| import::deploy_from_hex(vector.blob()).ok()),
+        );
+    }
+    Ok((deploys, vector_count))
+}
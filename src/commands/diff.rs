@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{
+    cli::DiffArgs,
+    ledger::{self, DisplayedElement, ZondaxRepr},
+};
+
+use super::read_vectors;
+
+/// Differences between two sets of test vectors, matched by their stable identifiers.
+#[derive(Default, Serialize)]
+struct DiffReport {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<ChangedVector>,
+}
+
+#[derive(Serialize)]
+struct ChangedVector {
+    id: String,
+    blob_changed: bool,
+    validity_changed: bool,
+    regular: Vec<ElementChange>,
+    expert: Vec<ElementChange>,
+}
+
+/// Change of the element at `index`. `None` when the element is missing from one of the sets.
+#[derive(Serialize)]
+struct ElementChange {
+    index: usize,
+    old: Option<DisplayedElement>,
+    new: Option<DisplayedElement>,
+}
+
+impl DiffReport {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn print(&self) {
+        for id in &self.removed {
+            println!("- {}", id);
+        }
+        for id in &self.added {
+            println!("+ {}", id);
+        }
+        for changed in &self.changed {
+            println!("~ {}", changed.id);
+            if changed.blob_changed {
+                println!("    blob changed");
+            }
+            if changed.validity_changed {
+                println!("    validity changed");
+            }
+            for (mode, changes) in [("regular", &changed.regular), ("expert", &changed.expert)] {
+                for change in changes {
+                    println!(
+                        "    {} #{}: {} -> {}",
+                        mode,
+                        change.index,
+                        describe(&change.old),
+                        describe(&change.new)
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Reports vectors that were added, removed or changed between the two sets,
+/// failing when there are any differences.
+pub(crate) fn run(args: &DiffArgs) -> Result<(), String> {
+    let old = read_vectors(&args.old)?;
+    let new = read_vectors(&args.new)?;
+    let report = diff(&old, &new);
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        report.print();
+    }
+
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "test vectors differ: {} added, {} removed, {} changed",
+            report.added.len(),
+            report.removed.len(),
+            report.changed.len()
+        ))
+    }
+}
+
+fn diff(old: &[ZondaxRepr], new: &[ZondaxRepr]) -> DiffReport {
+    let old_by_id = by_id(old);
+    let new_by_id = by_id(new);
+
+    let mut report = DiffReport::default();
+    for (id, old_vector) in &old_by_id {
+        match new_by_id.get(id) {
+            None => report.removed.push(id.to_string()),
+            Some(new_vector) => {
+                // Index isn't compared – it changes whenever a sample is added before the vector.
+                let changed = ChangedVector {
+                    id: id.to_string(),
+                    blob_changed: old_vector.blob() != new_vector.blob(),
+                    validity_changed: old_vector.valid_regular() != new_vector.valid_regular()
+                        || old_vector.valid_expert() != new_vector.valid_expert(),
+                    regular: element_changes(old_vector.output(), new_vector.output()),
+                    expert: element_changes(old_vector.output_expert(), new_vector.output_expert()),
+                };
+                if changed.blob_changed
+                    || changed.validity_changed
+                    || !changed.regular.is_empty()
+                    || !changed.expert.is_empty()
+                {
+                    report.changed.push(changed);
+                }
+            }
+        }
+    }
+    for id in new_by_id.keys() {
+        if !old_by_id.contains_key(id) {
+            report.added.push(id.to_string());
+        }
+    }
+    report
+}
+
+fn by_id(vectors: &[ZondaxRepr]) -> BTreeMap<&str, &ZondaxRepr> {
+    vectors.iter().map(|vector| (vector.id(), vector)).collect()
+}
+
+fn element_changes(old_pages: &[String], new_pages: &[String]) -> Vec<ElementChange> {
+    let old_elements = ledger::displayed_elements(old_pages);
+    let new_elements = ledger::displayed_elements(new_pages);
+    (0..old_elements.len().max(new_elements.len()))
+        .map(|index| ElementChange {
+            index,
+            old: old_elements.get(index).cloned(),
+            new: new_elements.get(index).cloned(),
+        })
+        .filter(|change| change.old != change.new)
+        .collect()
+}
+
+fn describe(element: &Option<DisplayedElement>) -> String {
+    match element {
+        Some(element) => format!("{}: {}", element.label, element.value),
+        None => "(none)".to_string(),
+    }
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_hashing::Digest;
+use casper_node::types::Deploy;
+use casper_types::{testing::TestRng, U512};
+use rand::SeedableRng;
+
+use crate::{
+    aliases::LabelAliases,
+    cli::GenerateArgs,
+    config::GeneratorConfig,
+    currency::Currencies,
+    fuzz, import,
+    ledger::{self, LimitedLedgerConfig, ZondaxRepr},
+    manifest::Manifest,
+    output::{self, dir::StagedDir, VectorWriter},
+    sample::{Sample, StableIds},
+    test_data::{
+        deploy_samples,
+        sign_message::{invalid_casper_message_sample, valid_casper_message_sample},
+    },
+};
+
+pub(crate) fn run(args: &GenerateArgs) -> Result<(), String> {
+    let args = &match &args.config {
+        Some(path) => args.clone().merged_with(GeneratorConfig::read(path)?)?,
+        None => args.clone(),
+    };
+    match &args.out_dir {
+        Some(out_dir) => {
+            let staged_dir = StagedDir::create(out_dir, args.dir_mode())
+                .map_err(|err| format!("failed to prepare {}: {}", out_dir.display(), err))?;
+            // Nothing is moved into the output directory unless all the vectors were written.
+            generate(&args.redirected_to(staged_dir.path()))?;
+            staged_dir
+                .commit()
+                .map_err(|err| format!("failed to write {}: {}", out_dir.display(), err))
+        }
+        None => generate(args),
+    }
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let imported_samples = match &args.import_dir {
+        Some(dir) => import::import_deploys(dir)?,
+        None => vec![],
+    };
+
+    let mut rng = match args.seed {
+        Some(seed) => TestRng::from_seed(seed),
+        None => TestRng::new(),
+    };
+
+    let mut limited_ledger_config = LimitedLedgerConfig::new(args.page_limit());
+    if let Some(limit) = args.element_page_limit {
+        limited_ledger_config = limited_ledger_config.with_element_page_limit(limit);
+    }
+    if let Some(path) = &args.label_aliases {
+        limited_ledger_config =
+            limited_ledger_config.with_label_aliases(Arc::new(LabelAliases::read(path)?));
+    }
+    if let Some(motes) = args.warn_above {
+        limited_ledger_config =
+            limited_ledger_config.with_amount_warning_threshold(U512::from(motes));
+    }
+    if let Some(offset) = args.timezone_offset {
+        limited_ledger_config = limited_ledger_config.with_timezone_offset(offset);
+    }
+    if let Some(path) = &args.currencies {
+        limited_ledger_config =
+            limited_ledger_config.with_currencies(Arc::new(Currencies::read(path)?));
+    }
+    if args.sections {
+        limited_ledger_config = limited_ledger_config.with_sections();
+    }
+
+    let mut id = 0;
+    let mut writer = VectorWriter::open(args.format(), args.out.as_deref())
+        .map_err(|err| format!("failed to open the output: {}", err))?;
+    let mut manifest = Manifest::new();
+    let mut stable_ids = StableIds::default();
+
+    // All samples are generated (and indexed) even when filtered out,
+    // so that a subset of vectors is identical to its counterpart in the whole corpus.
+    for sample_deploy in deploy_samples(&mut rng).into_iter().chain(imported_samples) {
+        let stable_id = stable_ids.assign(sample_deploy.label());
+        if args.includes_deploy(sample_deploy.sample()) {
+            let repr = match deploy_vector(
+                args,
+                id,
+                stable_id.clone(),
+                &sample_deploy,
+                &limited_ledger_config,
+            )? {
+                Some(repr) => repr,
+                None => {
+                    id += 1;
+                    continue;
+                }
+            };
+            manifest.push_deploy(sample_deploy.sample(), &repr);
+            // Messages are signed with a different instruction, so only deploys have transcripts.
+            // Vectors of oversized deploys have no blob to transmit.
+            if let (Some(apdu_dir), false) = (&args.apdu_dir, repr.blob().is_empty()) {
+                output::apdus::write_transcript(apdu_dir, &repr)
+                    .map_err(|err| format!("failed to write {}: {}", apdu_dir.display(), err))?;
+            }
+            emit(args, &mut writer, repr)?;
+        }
+        id += 1;
+    }
+
+    if args.includes_messages() {
+        for sample_casper_message in valid_casper_message_sample()
+            .into_iter()
+            .chain(invalid_casper_message_sample())
+        {
+            let stable_id = stable_ids.assign(sample_casper_message.label());
+            let repr = ledger::message_to_json(
+                id,
+                stable_id,
+                sample_casper_message,
+                &limited_ledger_config,
+            );
+            manifest.push_message(&repr);
+            emit(args, &mut writer, repr)?;
+            id += 1;
+        }
+    }
+
+    writer
+        .finish()
+        .map_err(|err| format!("failed to write test vectors: {}", err))?;
+
+    if let Some(index_path) = &args.index {
+        let manifest_json = serde_json::to_string_pretty(&manifest).unwrap();
+        std::fs::write(index_path, manifest_json)
+            .map_err(|err| format!("failed to write {}: {}", index_path.display(), err))?;
+    }
+    Ok(())
+}
+
+/// Renders the vector of the deploy, or `None` if the deploy can't be rendered and is skipped.
+fn deploy_vector(
+    args: &GenerateArgs,
+    id: usize,
+    stable_id: String,
+    sample_deploy: &Sample<Deploy>,
+    config: &LimitedLedgerConfig,
+) -> Result<Option<ZondaxRepr>, String> {
+    if let Some(limit) = args.max_module_bytes {
+        if let Some((len, hash)) = oversized_module_bytes(sample_deploy.sample(), limit) {
+            // Neither serialized nor rendered, so huge inputs can't exhaust the memory.
+            return Ok(Some(ledger::deploy_to_oversized_json(
+                id,
+                stable_id,
+                sample_deploy,
+                config,
+                len,
+                hash,
+            )));
+        }
+    }
+    // Samples marked as non-displayable cover the device's blind signing path.
+    if args.blind_signing && !sample_deploy.is_displayable() {
+        return ledger::deploy_to_blind_json(id, stable_id.clone(), sample_deploy, config)
+            .map(Some)
+            .map_err(|err| format!("failed to render {}: {}", stable_id, err));
+    }
+    match ledger::deploy_to_json(id, stable_id.clone(), sample_deploy, config) {
+        Ok(repr) => Ok(Some(repr)),
+        // The device falls back to displaying the hash of what it can't parse.
+        Err(_) if args.blind_signing => {
+            ledger::deploy_to_blind_json(id, stable_id.clone(), sample_deploy, config)
+                .map(Some)
+                .map_err(|err| format!("failed to render {}: {}", stable_id, err))
+        }
+        Err(err) => {
+            // A single broken sample shouldn't cost the whole vector set.
+            eprintln!("skipping sample {}: {}", stable_id, err);
+            Ok(None)
+        }
+    }
+}
+
+/// Returns the length and hash of the first module bytes of the deploy exceeding the `limit`.
+fn oversized_module_bytes(deploy: &Deploy, limit: usize) -> Option<(usize, Digest)> {
+    [deploy.payment(), deploy.session()]
+        .iter()
+        .find_map(|item| match item {
+            ExecutableDeployItem::ModuleBytes { module_bytes, .. }
+                if module_bytes.inner_bytes().len() > limit =>
+            {
+                Some((
+                    module_bytes.inner_bytes().len(),
+                    Digest::hash(module_bytes.as_slice()),
+                ))
+            }
+            _ => None,
+        })
+}
+
+/// Writes the test vector to all the requested outputs.
+fn emit(args: &GenerateArgs, writer: &mut VectorWriter, repr: ZondaxRepr) -> Result<(), String> {
+    let repr = repr.retain_modes(args.modes.includes_regular(), args.modes.includes_expert());
+    if let Some(zemu_dir) = &args.zemu_dir {
+        output::zemu::write_snapshot(zemu_dir, &repr)
+            .map_err(|err| format!("failed to write {}: {}", zemu_dir.display(), err))?;
+    }
+    // Only the pages are known of the vectors without a blob.
+    let has_blob = !repr.blob().is_empty();
+    if let (Some(blob_dir), true) = (&args.blob_dir, has_blob) {
+        output::blobs::write_pair(blob_dir, &repr)
+            .map_err(|err| format!("failed to write {}: {}", blob_dir.display(), err))?;
+    }
+    if let (Some(corpus_dir), true) = (&args.fuzz_corpus, has_blob) {
+        fuzz::write_corpus_entry(corpus_dir, &repr)
+            .map_err(|err| format!("failed to write {}: {}", corpus_dir.display(), err))?;
+    }
+    writer
+        .push(repr)
+        .map_err(|err| format!("failed to write test vectors: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::testing::TestRng;
+
+    use crate::{
+        cli::GenerateArgs,
+        ledger::{LimitedLedgerConfig, DEFAULT_PAGE_LIMIT},
+        test_data::deploy_samples,
+    };
+
+    use super::{deploy_vector, oversized_module_bytes};
+
+    #[test]
+    fn oversized_module_bytes_are_described_by_length_and_hash() {
+        let mut rng = TestRng::new();
+        let args = GenerateArgs {
+            max_module_bytes: Some(16),
+            ..GenerateArgs::default()
+        };
+        let config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+        let sample = deploy_samples(&mut rng)
+            .into_iter()
+            .find(|sample| oversized_module_bytes(sample.sample(), 16).is_some())
+            .expect("a sample with module bytes above the limit");
+
+        let repr = deploy_vector(&args, 0, "oversized".to_string(), &sample, &config)
+            .unwrap()
+            .expect("a vector of the oversized deploy");
+        assert!(repr.blob().is_empty());
+        assert!(repr
+            .output()
+            .iter()
+            .any(|page| page.contains("Module size")));
+        assert!(repr
+            .output()
+            .iter()
+            .any(|page| page.contains("Module hash")));
+    }
+
+    #[test]
+    fn non_displayable_samples_are_signed_blindly() {
+        let mut rng = TestRng::new();
+        let args = GenerateArgs {
+            blind_signing: true,
+            ..GenerateArgs::default()
+        };
+        let config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+        let blind_vectors: Vec<_> = deploy_samples(&mut rng)
+            .into_iter()
+            .filter(|sample| !sample.is_displayable())
+            .map(|sample| {
+                deploy_vector(&args, 0, sample.label().to_string(), &sample, &config)
+                    .unwrap()
+                    .expect("a vector of the non-displayable deploy")
+            })
+            .collect();
+        assert!(!blind_vectors.is_empty());
+        // Only the hash of the deploy is displayed.
+        for repr in blind_vectors {
+            assert!(repr.output().iter().all(|page| page.contains("Txn hash")));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+    sync::Arc,
+};
+
+use casper_node::types::Deploy;
+use casper_types::U512;
+
+use crate::{
+    abi::Abi,
+    aliases::LabelAliases,
+    cli::ParseArgs,
+    currency::Currencies,
+    import,
+    ledger::{self, LimitedLedgerConfig, ZondaxRepr, DEFAULT_PAGE_LIMIT},
+    output::VectorWriter,
+    policy::Policy,
+    registry::Registry,
+    rpc,
+    sample::Sample,
+};
+
+/// Prints pages that the Ledger displays for the deploy, in the requested format.
+pub(crate) fn run(args: &ParseArgs) -> Result<(), String> {
+    let (name, deploy) = match (&args.node_url, &args.hash) {
+        (Some(node_url), Some(hash)) => (hash.clone(), rpc::get_deploy(node_url, hash)?),
+        _ => read_deploy(args)?,
+    };
+
+    let mut config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+    if let Some(path) = &args.registry {
+        config = config.with_registry(Arc::new(Registry::read(path)?));
+    }
+    if args.verify_signatures {
+        config = config.with_signature_verification();
+    }
+    if args.redact {
+        config = config.with_redaction();
+    }
+    if args.debug {
+        config = config.with_debug_elements();
+    }
+    if args.sections {
+        config = config.with_sections();
+    }
+    if let Some(path) = &args.abi {
+        config = config.with_recognizer(Arc::new(Abi::read(path)?));
+    }
+    if let Some(path) = &args.label_aliases {
+        config = config.with_label_aliases(Arc::new(LabelAliases::read(path)?));
+    }
+    if let Some(motes) = args.warn_above {
+        config = config.with_amount_warning_threshold(U512::from(motes));
+    }
+    if let Some(offset) = args.timezone_offset {
+        config = config.with_timezone_offset(offset);
+    }
+    if let Some(path) = &args.currencies {
+        config = config.with_currencies(Arc::new(Currencies::read(path)?));
+    }
+    if let Some(path) = &args.policy {
+        config = config.with_policy(Arc::new(Policy::read(path)?));
+    }
+    let repr = deploy_repr(name, deploy, &config)?
+        .retain_modes(args.modes.includes_regular(), args.modes.includes_expert());
+    let mut writer = VectorWriter::open(args.format, None)
+        .map_err(|err| format!("failed to open the output: {}", err))?;
+    writer
+        .push(repr)
+        .and_then(|()| writer.finish())
+        .map_err(|err| format!("failed to write the output: {}", err))
+}
+
+/// Renders a single deploy as a test vector, identified by the `name`.
+pub(super) fn deploy_repr(
+    name: String,
+    deploy: Deploy,
+    config: &LimitedLedgerConfig,
+) -> Result<ZondaxRepr, String> {
+    ledger::deploy_to_json(
+        0,
+        name.clone(),
+        &Sample::new(name.clone(), deploy, true),
+        config,
+    )
+    .map_err(|err| format!("failed to render {}: {}", name, err))
+}
+
+// Returns the deploy along with the name of its input.
+fn read_deploy(args: &ParseArgs) -> Result<(String, Deploy), String> {
+    let input = args.input.as_deref().unwrap_or_else(|| Path::new("-"));
+    let content =
+        read_input(input).map_err(|err| format!("failed to read {}: {}", input.display(), err))?;
+    let deploy = if args.hex {
+        import::deploy_from_hex(&content)
+    } else {
+        import::deploy_from_json(&content)
+    }
+    .map_err(|err| format!("failed to parse {}: {}", input.display(), err))?;
+
+    let name = match input.file_stem() {
+        Some(stem) if input != Path::new("-") => stem.to_string_lossy().into_owned(),
+        _ => "stdin".to_string(),
+    };
+    Ok((name, deploy))
+}
+
+// Reads the file at `path`, or the standard input when it is `-`.
+fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)?;
+        Ok(content)
+    } else {
+        fs::read_to_string(path)
+    }
+}
@@ -0,0 +1,47 @@
+use std::fs;
+
+use crate::{
+    cli::{GenerateArgs, SyncArgs},
+    output::dir::{DirMode, StagedDir},
+    test_data::VECTORS_SEED,
+};
+
+/// Test vectors read by the app's unit tests.
+const VECTORS_PATH: &str = "tests/manual.json";
+/// Expected screens of the app's Zemu tests.
+const ZEMU_DIR: &str = "tests_zemu/snapshots-txt";
+/// Seed corpus of the app's parser fuzzer.
+const FUZZ_CORPUS_DIR: &str = "fuzz/corpora/parser_parse";
+
+/// Writes the whole vector set into the `ledger-casper` repository's layout,
+/// touching only the files whose content changed.
+pub(crate) fn run(args: &SyncArgs) -> Result<(), String> {
+    let app_dir = &args.ledger_app_dir;
+    if !app_dir.join("tests").is_dir() {
+        return Err(format!(
+            "{} doesn't look like a ledger-casper checkout, it has no tests directory",
+            app_dir.display()
+        ));
+    }
+
+    let staged_dir = StagedDir::create(app_dir, Some(DirMode::Merge))
+        .map_err(|err| format!("failed to prepare {}: {}", app_dir.display(), err))?;
+    let staging = staged_dir.path();
+    let vectors_path = staging.join(VECTORS_PATH);
+    if let Some(vectors_dir) = vectors_path.parent() {
+        fs::create_dir_all(vectors_dir)
+            .map_err(|err| format!("failed to create {}: {}", vectors_dir.display(), err))?;
+    }
+    super::generate::run(&GenerateArgs {
+        import_dir: args.import_dir.clone(),
+        out: Some(vectors_path),
+        zemu_dir: Some(staging.join(ZEMU_DIR)),
+        fuzz_corpus: Some(staging.join(FUZZ_CORPUS_DIR)),
+        // The same samples as the committed vectors, so that a sync shows only actual changes.
+        seed: Some(VECTORS_SEED),
+        ..GenerateArgs::default()
+    })?;
+    staged_dir
+        .commit()
+        .map_err(|err| format!("failed to write {}: {}", app_dir.display(), err))
+}
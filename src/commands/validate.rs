@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use crate::{
+    cli::ValidateArgs,
+    ledger::{
+        self, is_printable, ZondaxRepr, LEDGER_VIEW_BOTTOM_CHAR_COUNT, LEDGER_VIEW_NAME_CHAR_COUNT,
+        LEDGER_VIEW_TOP_ROW_CHAR_COUNT,
+    },
+};
+
+use super::read_vectors;
+
+/// Checks that the vectors are well-formed and that every element fits the device's display:
+/// only printable ASCII characters, labels within the label row, and a limited number
+/// of pages per element and elements per sample. Prints violations found in every sample.
+pub(crate) fn run(args: &ValidateArgs) -> Result<(), String> {
+    let vectors = read_vectors(&args.vectors)?;
+
+    let mut ids = HashSet::new();
+    let mut invalid_count = 0;
+    for vector in &vectors {
+        let mut violations = violations(args, vector);
+        if !ids.insert(vector.id()) {
+            violations.push("duplicated identifier".to_string());
+        }
+        if !violations.is_empty() {
+            invalid_count += 1;
+            println!("{}:", vector.id());
+            for violation in &violations {
+                println!("  {}", violation);
+            }
+        }
+    }
+
+    match invalid_count {
+        0 => Ok(()),
+        count => Err(format!(
+            "{} of {} test vectors are invalid",
+            count,
+            vectors.len()
+        )),
+    }
+}
+
+fn violations(args: &ValidateArgs, vector: &ZondaxRepr) -> Vec<String> {
+    let mut violations = vec![];
+    if hex::decode(vector.blob()).is_err() {
+        violations.push("blob is not hex-encoded".to_string());
+    }
+    for (mode, pages) in [
+        ("regular", vector.output()),
+        ("expert", vector.output_expert()),
+    ] {
+        if pages.is_empty() {
+            violations.push(format!("no pages in {} mode", mode));
+        }
+        let elements = ledger::displayed_elements(pages);
+        if elements.len() > args.max_elements {
+            violations.push(format!(
+                "{} elements in {} mode, more than {}",
+                elements.len(),
+                mode,
+                args.max_elements
+            ));
+        }
+        for (idx, element) in elements.iter().enumerate() {
+            let mut violation = |message: String| {
+                violations.push(format!(
+                    "{} #{} ({}): {}",
+                    mode, idx, element.label, message
+                ))
+            };
+            if !is_printable(&element.label) || !is_printable(&element.value) {
+                violation("contains non-printable characters".to_string());
+            }
+            if element.label.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT {
+                violation(format!(
+                    "label longer than {} characters",
+                    LEDGER_VIEW_NAME_CHAR_COUNT
+                ));
+            }
+            if element.page_count > args.max_element_pages {
+                violation(format!(
+                    "spans {} pages, more than {}",
+                    element.page_count, args.max_element_pages
+                ));
+            }
+            let page_char_count = LEDGER_VIEW_TOP_ROW_CHAR_COUNT + LEDGER_VIEW_BOTTOM_CHAR_COUNT;
+            if element.value.chars().count() > element.page_count * page_char_count {
+                violation("value doesn't fit its pages".to_string());
+            }
+        }
+    }
+    violations
+}
@@ -0,0 +1,59 @@
+use std::io::{self, BufRead, BufReader};
+
+use serde_json::Value;
+
+use crate::{
+    checksummed_hex,
+    cli::WatchArgs,
+    import,
+    ledger::{LimitedLedgerConfig, DEFAULT_PAGE_LIMIT},
+    output::text,
+};
+
+use super::parse::deploy_repr;
+
+/// Prints pages that the Ledger displays for every deploy accepted by the node,
+/// until the node closes the event stream.
+///
+/// Events that can't be rendered are reported and skipped, so that a single unsupported
+/// transaction doesn't stop the watch.
+pub(crate) fn run(args: &WatchArgs) -> Result<(), String> {
+    let response = ureq::get(&args.events_url)
+        .call()
+        .map_err(|err| format!("failed to connect to {}: {}", args.events_url, err))?;
+
+    let config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+    for line in BufReader::new(response.into_reader()).lines() {
+        let line = line.map_err(|err| format!("failed to read the event stream: {}", err))?;
+        let event = match line.strip_prefix("data:") {
+            Some(data) => data,
+            // Event ids, comments and keep-alives.
+            None => continue,
+        };
+        let mut event: Value = match serde_json::from_str(event) {
+            Ok(event) => event,
+            Err(err) => {
+                eprintln!("skipping malformed event: {}", err);
+                continue;
+            }
+        };
+        // `DeployAccepted` is emitted by nodes before 2.0, `TransactionAccepted` by newer ones.
+        let accepted = if event.get("DeployAccepted").is_some() {
+            event["DeployAccepted"].take()
+        } else if event.get("TransactionAccepted").is_some() {
+            event["TransactionAccepted"].take()
+        } else {
+            continue;
+        };
+        let repr = import::deploy_from_value(accepted).and_then(|deploy| {
+            let hash = checksummed_hex::encode(deploy.id().inner());
+            deploy_repr(hash, deploy, &config)
+        });
+        match repr {
+            Ok(repr) => text::write(&mut io::stdout().lock(), &repr)
+                .map_err(|err| format!("failed to write the output: {}", err))?,
+            Err(err) => eprintln!("skipping accepted transaction: {}", err),
+        }
+    }
+    Ok(())
+}
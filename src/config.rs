@@ -0,0 +1,88 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{cli::SampleCategory, output::OutputFormat};
+
+/// Generator options read from a configuration file (`generator.toml` by convention),
+/// so that teams can check in a single configuration instead of long command lines.
+///
+/// Options given on the command line take precedence over the ones from the file.
+/// Relative paths are resolved against the working directory.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct GeneratorConfig {
+    pub(crate) device: DeviceConfig,
+    pub(crate) output: OutputConfig,
+}
+
+/// Profile of the device the vectors are generated for.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct DeviceConfig {
+    /// Number of pages after which the Ledger suggests switching to the expert mode.
+    pub(crate) page_limit: Option<u8>,
+    /// Number of pages above which values are truncated, as the device's memory allows.
+    pub(crate) element_page_limit: Option<u8>,
+}
+
+/// Options of the generated output, the same as the command line flags of `generate`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct OutputConfig {
+    pub(crate) only: Vec<SampleCategory>,
+    pub(crate) format: Option<OutputFormat>,
+    pub(crate) out: Option<PathBuf>,
+    pub(crate) out_dir: Option<PathBuf>,
+    pub(crate) index: Option<PathBuf>,
+    pub(crate) import_dir: Option<PathBuf>,
+    pub(crate) fuzz_corpus: Option<PathBuf>,
+    pub(crate) zemu_dir: Option<PathBuf>,
+    pub(crate) blob_dir: Option<PathBuf>,
+    pub(crate) apdu_dir: Option<PathBuf>,
+    pub(crate) max_module_bytes: Option<usize>,
+    pub(crate) blind_signing: bool,
+    pub(crate) label_aliases: Option<PathBuf>,
+    pub(crate) warn_above: Option<u64>,
+    pub(crate) currencies: Option<PathBuf>,
+    pub(crate) timezone_offset: Option<String>,
+    pub(crate) sections: bool,
+    pub(crate) regular_only: bool,
+    pub(crate) expert: bool,
+}
+
+impl GeneratorConfig {
+    pub(crate) fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        toml::from_str(&content).map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneratorConfig;
+    use crate::output::OutputFormat;
+
+    #[test]
+    fn rejects_unknown_options() {
+        let config: GeneratorConfig = toml::from_str(
+            r#"
+            [device]
+            page_limit = 10
+
+            [output]
+            format = "c-header"
+            only = ["transfers"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.device.page_limit, Some(10));
+        assert_eq!(config.output.format, Some(OutputFormat::CHeader));
+
+        assert!(toml::from_str::<GeneratorConfig>("[output]\nformats = \"json\"").is_err());
+    }
+}
@@ -0,0 +1,314 @@
+//! Checks that every field of the samples reaches the screen: changing any of them
+//! (or removing any argument) must change the displayed elements.
+//!
+//! Fields deliberately not displayed:
+//! * the body hash is displayed, but it changes with the payment and session,
+//!   so it's left out of the comparison too,
+//! * the signatures of the approvals – only their signers and algorithms are displayed,
+//!   the signatures sign the deploy hash,
+//! * the deploy hash itself is displayed, but it changes with every other field,
+//!   so it's left out of the comparison.
+
+use std::collections::BTreeMap;
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::{Deploy, DeployHash};
+use casper_types::{
+    bytesrepr::Bytes, testing::TestRng, CLValue, ContractHash, ContractPackageHash, PublicKey,
+    RuntimeArgs, SecretKey, TimeDiff, Timestamp,
+};
+
+use crate::{ledger::Element, parser, test_data};
+
+// Labels of the elements that change along with any other field.
+const DERIVED_LABELS: [&str; 3] = ["Txn hash", "Body hash", "Approvals #"];
+
+/// Fields the deploy is built from.
+#[derive(Clone)]
+struct DeployFields {
+    account: PublicKey,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    gas_price: u64,
+    dependencies: Vec<DeployHash>,
+    chain_name: String,
+    payment: ExecutableDeployItem,
+    session: ExecutableDeployItem,
+}
+
+impl DeployFields {
+    fn of(deploy: &Deploy) -> Self {
+        let header = deploy.header();
+        DeployFields {
+            account: header.account().clone(),
+            timestamp: header.timestamp(),
+            ttl: header.ttl(),
+            gas_price: header.gas_price(),
+            dependencies: header.dependencies().clone(),
+            chain_name: header.chain_name().to_string(),
+            payment: deploy.payment().clone(),
+            session: deploy.session().clone(),
+        }
+    }
+
+    fn to_deploy(&self) -> Deploy {
+        // The signer isn't displayed, only the account is.
+        let secret_key = SecretKey::ed25519_from_bytes([1u8; 32]).unwrap();
+        Deploy::new(
+            self.timestamp,
+            self.ttl,
+            self.gas_price,
+            self.dependencies.clone(),
+            self.chain_name.clone(),
+            self.payment.clone(),
+            self.session.clone(),
+            &secret_key,
+            Some(self.account.clone()),
+        )
+    }
+
+    /// Returns copies of the fields, each with a single field changed, along with its name.
+    fn mutations(&self) -> Vec<(String, DeployFields)> {
+        let mut mutations = vec![];
+        let mut mutate = |name: &str, mutate: &dyn Fn(&mut DeployFields)| {
+            let mut fields = self.clone();
+            mutate(&mut fields);
+            mutations.push((name.to_string(), fields));
+        };
+        mutate("account", &|fields| {
+            fields.account = PublicKey::from(&SecretKey::ed25519_from_bytes([0xee; 32]).unwrap())
+        });
+        mutate("timestamp", &|fields| {
+            fields.timestamp = fields.timestamp + TimeDiff::from_seconds(1)
+        });
+        mutate("ttl", &|fields| {
+            fields.ttl = fields.ttl + TimeDiff::from_seconds(60)
+        });
+        mutate("gas price", &|fields| fields.gas_price += 1);
+        mutate("dependencies", &|fields| {
+            fields.dependencies.push(DeployHash::new([0xee; 32].into()))
+        });
+        mutate("chain name", &|fields| fields.chain_name.push('x'));
+
+        for (name, payment) in item_mutations(&self.payment) {
+            let mut fields = self.clone();
+            fields.payment = payment;
+            mutations.push((format!("payment {}", name), fields));
+        }
+        for (name, session) in item_mutations(&self.session) {
+            let mut fields = self.clone();
+            fields.session = session;
+            mutations.push((format!("session {}", name), fields));
+        }
+        mutations
+    }
+}
+
+/// Returns copies of the item, each with a single field changed or argument removed.
+fn item_mutations(item: &ExecutableDeployItem) -> Vec<(String, ExecutableDeployItem)> {
+    let mut mutations = vec![];
+    let args: BTreeMap<String, CLValue> = item.args().clone().into();
+    for name in args.keys() {
+        let mut remaining_args = args.clone();
+        remaining_args.remove(name);
+        mutations.push((
+            format!("without argument {}", name),
+            with_args(item, remaining_args.into()),
+        ));
+    }
+
+    match item {
+        ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+            let mut module_bytes = module_bytes.inner_bytes().clone();
+            module_bytes.push(0);
+            mutations.push((
+                "module bytes".to_string(),
+                ExecutableDeployItem::ModuleBytes {
+                    module_bytes: Bytes::from(module_bytes),
+                    args: args.clone(),
+                },
+            ));
+        }
+        ExecutableDeployItem::StoredContractByHash {
+            hash,
+            entry_point,
+            args,
+        } => {
+            mutations.push((
+                "hash".to_string(),
+                ExecutableDeployItem::StoredContractByHash {
+                    hash: ContractHash::new(flip_first_bit(hash.value())),
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ));
+            mutations.push((
+                "entry point".to_string(),
+                ExecutableDeployItem::StoredContractByHash {
+                    hash: *hash,
+                    entry_point: format!("{}x", entry_point),
+                    args: args.clone(),
+                },
+            ));
+        }
+        ExecutableDeployItem::StoredContractByName {
+            name,
+            entry_point,
+            args,
+        } => {
+            mutations.push((
+                "name".to_string(),
+                ExecutableDeployItem::StoredContractByName {
+                    name: format!("{}x", name),
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ));
+            mutations.push((
+                "entry point".to_string(),
+                ExecutableDeployItem::StoredContractByName {
+                    name: name.clone(),
+                    entry_point: format!("{}x", entry_point),
+                    args: args.clone(),
+                },
+            ));
+        }
+        ExecutableDeployItem::StoredVersionedContractByHash {
+            hash,
+            version,
+            entry_point,
+            args,
+        } => {
+            mutations.push((
+                "hash".to_string(),
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash: ContractPackageHash::new(flip_first_bit(hash.value())),
+                    version: *version,
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ));
+            mutations.push((
+                "version".to_string(),
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash: *hash,
+                    version: other_version(*version),
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ));
+            mutations.push((
+                "entry point".to_string(),
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash: *hash,
+                    version: *version,
+                    entry_point: format!("{}x", entry_point),
+                    args: args.clone(),
+                },
+            ));
+        }
+        ExecutableDeployItem::StoredVersionedContractByName {
+            name,
+            version,
+            entry_point,
+            args,
+        } => {
+            mutations.push((
+                "name".to_string(),
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name: format!("{}x", name),
+                    version: *version,
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ));
+            mutations.push((
+                "version".to_string(),
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name: name.clone(),
+                    version: other_version(*version),
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ));
+            mutations.push((
+                "entry point".to_string(),
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name: name.clone(),
+                    version: *version,
+                    entry_point: format!("{}x", entry_point),
+                    args: args.clone(),
+                },
+            ));
+        }
+        // All the fields of a transfer are its arguments.
+        ExecutableDeployItem::Transfer { .. } => {}
+    }
+    mutations
+}
+
+fn with_args(item: &ExecutableDeployItem, args: RuntimeArgs) -> ExecutableDeployItem {
+    let mut item = item.clone();
+    match &mut item {
+        ExecutableDeployItem::ModuleBytes {
+            args: item_args, ..
+        }
+        | ExecutableDeployItem::StoredContractByHash {
+            args: item_args, ..
+        }
+        | ExecutableDeployItem::StoredContractByName {
+            args: item_args, ..
+        }
+        | ExecutableDeployItem::StoredVersionedContractByHash {
+            args: item_args, ..
+        }
+        | ExecutableDeployItem::StoredVersionedContractByName {
+            args: item_args, ..
+        }
+        | ExecutableDeployItem::Transfer { args: item_args } => *item_args = args,
+    }
+    item
+}
+
+fn flip_first_bit(mut bytes: [u8; 32]) -> [u8; 32] {
+    bytes[0] ^= 1;
+    bytes
+}
+
+fn other_version(version: Option<u32>) -> Option<u32> {
+    match version {
+        Some(_) => None,
+        None => Some(1),
+    }
+}
+
+// Elements displayed for the deploy, leaving out the ones derived from all the fields.
+fn displayed(fields: &DeployFields) -> Option<Vec<Element>> {
+    let elements = parser::parse_deploy(&fields.to_deploy()).ok()?;
+    Some(
+        elements
+            .into_iter()
+            .filter(|element| !DERIVED_LABELS.contains(&element.label()))
+            .collect(),
+    )
+}
+
+#[test]
+fn every_field_reaches_the_screen() {
+    let mut rng = TestRng::new();
+    let mut hidden = vec![];
+    for sample in test_data::deploy_samples(&mut rng) {
+        let fields = DeployFields::of(sample.sample());
+        let original = displayed(&fields);
+        for (field, mutated) in fields.mutations() {
+            if displayed(&mutated) == original {
+                hidden.push(format!("{}: {}", sample.label(), field));
+            }
+        }
+    }
+    assert!(
+        hidden.is_empty(),
+        "changes that don't reach the screen:\n{}",
+        hidden.join("\n")
+    );
+}
@@ -0,0 +1,145 @@
+//! Currency the amounts are displayed in, by the name of the chain – so that vectors
+//! for integration networks, or for a change of the denomination, need no code edits.
+//!
+//! ```toml
+//! [chain.casper-test]
+//! ticker = "CSPR"
+//! # Amounts are in motes, displayed scaled by 10^decimals.
+//! decimals = 9
+//! ```
+
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use casper_types::U512;
+use serde::Deserialize;
+use thousands::Separable;
+
+use crate::{abi::scaled, ledger::is_printable};
+
+// Longest ticker that leaves the display room for the amount itself.
+const MAX_TICKER_CHAR_COUNT: usize = 8;
+// Decimals beyond that would scale any `U512` amount to a fraction.
+const MAX_DECIMALS: u32 = 78;
+
+/// Currency of the displayed amounts.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Currency {
+    ticker: String,
+    #[serde(default)]
+    decimals: u32,
+}
+
+impl Default for Currency {
+    /// Motes, the unit of the amounts in the deploys – which is what the device displays.
+    fn default() -> Self {
+        Currency {
+            ticker: "motes".to_string(),
+            decimals: 0,
+        }
+    }
+}
+
+impl Currency {
+    /// Formats the amount of motes, like `1 000 motes` or `0.000001 CSPR`.
+    pub(crate) fn format(&self, motes: U512) -> String {
+        let digits = motes.to_string();
+        let amount = if self.decimals == 0 {
+            digits.separate_with_spaces()
+        } else {
+            scaled(&digits, self.decimals)
+        };
+        format!("{} {}", amount, self.ticker)
+    }
+}
+
+/// Currencies by the name of the chain, read from a TOML or JSON file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "CurrenciesFile")]
+pub struct Currencies {
+    chains: BTreeMap<String, Currency>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CurrenciesFile {
+    #[serde(default, rename = "chain")]
+    chains: BTreeMap<String, Currency>,
+}
+
+impl TryFrom<CurrenciesFile> for Currencies {
+    type Error = String;
+
+    fn try_from(file: CurrenciesFile) -> Result<Self, Self::Error> {
+        for (chain_name, currency) in &file.chains {
+            if currency.ticker.chars().count() > MAX_TICKER_CHAR_COUNT
+                || !is_printable(&currency.ticker)
+            {
+                return Err(format!(
+                    "ticker {} of {} isn't printable in {} characters",
+                    currency.ticker, chain_name, MAX_TICKER_CHAR_COUNT
+                ));
+            }
+            if currency.decimals > MAX_DECIMALS {
+                return Err(format!(
+                    "{} decimals of {} are more than {}",
+                    currency.decimals, chain_name, MAX_DECIMALS
+                ));
+            }
+        }
+        Ok(Currencies {
+            chains: file.chains,
+        })
+    }
+}
+
+impl Currencies {
+    /// Reads the currencies from JSON, of the same structure as the TOML file.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads the currencies from the TOML (or, with the `.json` extension, JSON) file.
+    #[cfg(feature = "cli")]
+    pub(crate) fn read(path: &std::path::Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        if path.extension().map_or(false, |ext| ext == "json") {
+            Self::from_json(&content).map_err(|err| err.to_string())
+        } else {
+            toml::from_str(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    /// Returns the currency of the chain, if it's configured.
+    pub(crate) fn of(&self, chain_name: &str) -> Option<&Currency> {
+        self.chains.get(chain_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U512;
+
+    use super::{Currencies, Currency};
+
+    #[test]
+    fn amounts_are_formatted_in_the_chain_currency() {
+        let currencies = Currencies::from_json(
+            r#"{"chain": {"casper-test": { "ticker": "CSPR", "decimals": 9 }}}"#,
+        )
+        .unwrap();
+        let amount = U512::from(2_500_000_000u64);
+        assert_eq!(
+            currencies.of("casper-test").unwrap().format(amount),
+            "2.5 CSPR"
+        );
+        assert!(currencies.of("casper").is_none());
+        assert_eq!(Currency::default().format(amount), "2 500 000 000 motes");
+        assert!(
+            Currencies::from_json(r#"{"chain": {"casper": { "ticker": "casper tokens" }}}"#)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::{self, Display};
+
+/// Reason why a deploy can't be rendered into the elements displayed by the Ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Runtime argument whose value doesn't have the expected form.
+    InvalidArgument {
+        /// Name of the argument.
+        name: String,
+        /// What is wrong with its value.
+        reason: String,
+    },
+    /// Value that can't be turned into its displayed form.
+    InvalidValue(String),
+    /// Element the device can't display as is, rejected in strict mode.
+    NotDisplayable {
+        /// Label of the element.
+        label: String,
+        /// Why it can't be displayed.
+        reason: String,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidArgument { name, reason } => {
+                write!(f, "invalid argument {}: {}", name, reason)
+            }
+            ParseError::InvalidValue(reason) => write!(f, "invalid value: {}", reason),
+            ParseError::NotDisplayable { label, reason } => {
+                write!(f, "{} can't be displayed: {}", label, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
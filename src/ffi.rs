@@ -0,0 +1,56 @@
+use std::{
+    ffi::CString,
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+
+use casper_node::types::Deploy;
+use casper_types::bytesrepr;
+
+use crate::Options;
+
+/// Returns the elements the Ledger displays for the serialized deploy,
+/// as a NUL-terminated JSON array of `{ "label", "value", "expert" }` objects.
+///
+/// Returns NULL when the bytes aren't a valid deploy or it can't be rendered.
+/// The returned string must be released with `casper_free_elements`.
+///
+/// # Safety
+///
+/// `blob` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_deploy_to_elements(
+    blob: *const u8,
+    len: usize,
+    expert: bool,
+) -> *mut c_char {
+    if blob.is_null() {
+        return ptr::null_mut();
+    }
+    let bytes = slice::from_raw_parts(blob, len).to_vec();
+    // Panics must not unwind into the C caller.
+    let elements = panic::catch_unwind(AssertUnwindSafe(|| {
+        let deploy: Deploy = bytesrepr::deserialize(bytes).ok()?;
+        let elements =
+            crate::deploy_to_elements(&deploy, &Options::default().expert(expert)).ok()?;
+        serde_json::to_string(&elements).ok()
+    }));
+    match elements {
+        Ok(Some(json)) => CString::new(json).map_or(ptr::null_mut(), CString::into_raw),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Releases the string returned by `casper_deploy_to_elements`.
+///
+/// # Safety
+///
+/// `elements` must be NULL or a pointer returned by `casper_deploy_to_elements`,
+/// not released before.
+#[no_mangle]
+pub unsafe extern "C" fn casper_free_elements(elements: *mut c_char) {
+    if !elements.is_null() {
+        drop(CString::from_raw(elements));
+    }
+}
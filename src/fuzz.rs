@@ -0,0 +1,49 @@
+use std::{fs, io, path::Path};
+
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+use crate::ledger::ZondaxRepr;
+
+/// Writes raw bytes of the sample into the corpus directory,
+/// together with a few near-valid mutations of it.
+///
+/// Mutations are seeded with the stable identifier of the sample, so that the corpus
+/// is the same for every run of the generator.
+pub(crate) fn write_corpus_entry(dir: &Path, repr: &ZondaxRepr) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let blob = hex::decode(repr.blob()).expect("blob to be hex-encoded");
+    fs::write(dir.join(repr.id()), &blob)?;
+
+    let mut rng = Pcg64::seed_from_u64(seed(repr.id()));
+    for (idx, mutated) in mutations(&mut rng, &blob).into_iter().enumerate() {
+        fs::write(dir.join(format!("{}_mutated_{}", repr.id(), idx)), mutated)?;
+    }
+    Ok(())
+}
+
+// FNV-1a hash of the identifier – unlike `DefaultHasher`, guaranteed not to change between releases.
+fn seed(id: &str) -> u64 {
+    id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Returns near-valid variants of the blob: with a single bit flipped,
+/// truncated and with a random byte inserted.
+fn mutations<R: Rng>(rng: &mut R, blob: &[u8]) -> Vec<Vec<u8>> {
+    if blob.is_empty() {
+        return vec![];
+    }
+
+    let mut bit_flipped = blob.to_vec();
+    let flipped_position = rng.gen_range(0..blob.len());
+    bit_flipped[flipped_position] ^= 1u8 << rng.gen_range(0..8u32);
+
+    let truncated = blob[..rng.gen_range(0..blob.len())].to_vec();
+
+    let mut extended = blob.to_vec();
+    extended.insert(rng.gen_range(0..=blob.len()), rng.gen());
+
+    vec![bit_flipped, truncated, extended]
+}
@@ -0,0 +1,143 @@
+//! Golden snapshots of the elements rendered for every sample, stored under `tests/goldens/`,
+//! and the digest of the whole vector set, stored in `tests/vectors.digest`.
+//!
+//! Run the tests with `UPDATE_GOLDENS=1` to accept intentional changes,
+//! so that they show up in review as diffs of the golden files.
+
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use casper_hashing::Digest;
+use casper_types::testing::TestRng;
+use rand::SeedableRng;
+
+use crate::{
+    ledger::{self, Element, LimitedLedgerConfig, DEFAULT_PAGE_LIMIT},
+    output::json,
+    parser,
+    sample::StableIds,
+    test_data,
+};
+
+const UPDATE_VAR: &str = "UPDATE_GOLDENS";
+
+fn goldens_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("goldens")
+}
+
+fn digest_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("vectors.digest")
+}
+
+fn render(elements: &[Element]) -> String {
+    elements
+        .iter()
+        .map(|element| {
+            let mode = if element.is_expert() {
+                "expert"
+            } else {
+                "regular"
+            };
+            format!("{} | {}: {}\n", mode, element.label(), element.value())
+        })
+        .collect()
+}
+
+#[test]
+fn elements_match_goldens() {
+    let update = env::var_os(UPDATE_VAR).is_some();
+    let dir = goldens_dir();
+    if update {
+        fs::create_dir_all(&dir).unwrap();
+    }
+
+    let mut rng = TestRng::from_seed(test_data::VECTORS_SEED);
+    let mut stable_ids = StableIds::default();
+    let mut golden_files = BTreeSet::new();
+    let mut mismatches = vec![];
+    for sample in test_data::deploy_samples(&mut rng) {
+        let id = stable_ids.assign(sample.label());
+        let rendered = match parser::parse_deploy(sample.sample()) {
+            Ok(elements) => render(&elements),
+            Err(err) => format!("error: {}\n", err),
+        };
+        let file_name = format!("{}.txt", id);
+        let path = dir.join(&file_name);
+        golden_files.insert(file_name);
+        // Goldens are written on request only, so that a missing one fails like a changed one.
+        match fs::read_to_string(&path) {
+            Ok(golden) if golden == rendered => {}
+            _ if update => fs::write(&path, rendered).unwrap(),
+            Ok(_) => mismatches.push(id),
+            Err(_) => mismatches.push(format!("{} (no golden)", id)),
+        }
+    }
+
+    let entries = fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.unwrap());
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if golden_files.contains(&file_name) {
+            continue;
+        }
+        if update {
+            fs::remove_file(dir.join(&file_name)).unwrap();
+        } else {
+            mismatches.push(format!("{} (no such sample)", file_name));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "elements differ from the goldens of: {}\nrun the tests with {}=1 to accept the changes",
+        mismatches.join(", "),
+        UPDATE_VAR
+    );
+}
+
+// Guards against output depending on the platform – its line endings, locale
+// or iteration order of hash maps – rather than on the samples alone.
+#[test]
+fn vectors_match_digest() {
+    let mut rng = TestRng::from_seed(test_data::VECTORS_SEED);
+    let mut stable_ids = StableIds::default();
+    let config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+    let vectors: Vec<_> = test_data::deploy_samples(&mut rng)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, sample)| {
+            let id = stable_ids.assign(sample.label());
+            ledger::deploy_to_json(index, id, &sample, &config).ok()
+        })
+        .collect();
+    let mut output = vec![];
+    json::write(&mut output, &vectors).unwrap();
+    let digest = format!("{}\n", hex::encode(Digest::hash(&output)));
+
+    let path = digest_path();
+    match fs::read_to_string(&path) {
+        Ok(committed) if committed == digest => {}
+        _ if env::var_os(UPDATE_VAR).is_some() => fs::write(&path, digest).unwrap(),
+        Ok(committed) => panic!(
+            "digest of the test vectors changed from {} to {}\nrun the tests with {}=1 to accept the change",
+            committed.trim(),
+            digest.trim(),
+            UPDATE_VAR
+        ),
+        Err(err) => panic!(
+            "failed to read {}: {}\nrun the tests with {}=1 to record the digest",
+            path.display(),
+            err,
+            UPDATE_VAR
+        ),
+    }
+}
@@ -0,0 +1,106 @@
+use std::{fs, path::Path};
+
+use casper_node::types::Deploy;
+use casper_types::bytesrepr;
+
+use crate::sample::Sample;
+
+/// Reads every `*.json` file in the directory as a captured deploy and turns it into a sample.
+///
+/// Files may contain either a bare deploy or the JSON returned by the node's `info_get_deploy`
+/// (as printed by `casper-client get-deploy`). Deploys captured from the network
+/// have been accepted by it, so they are all considered valid.
+pub(crate) fn import_deploys(dir: &Path) -> Result<Vec<Sample<Deploy>>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|err| format!("failed to read directory {}: {}", dir.display(), err))?;
+
+    let mut paths = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("failed to read directory {}: {}", dir.display(), err))?
+            .path();
+        if path.extension().map_or(false, |ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    // Keep the order (and so the indices of vectors) independent of the file system.
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| -> Result<Sample<Deploy>, String> {
+            let content = fs::read_to_string(&path)
+                .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+            let deploy = deploy_from_json(&content)
+                .map_err(|err| format!("failed to parse {}: {}", path.display(), err))?;
+            let file_stem = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            Ok(Sample::new(
+                format!("imported__{}", file_stem),
+                deploy,
+                true,
+            ))
+        })
+        .collect()
+}
+
+/// Parses a deploy from its JSON representation, unwrapping it from the RPC request
+/// or response if needed.
+///
+/// `account_put_deploy` and `account_put_transaction` requests, as well as `info_get_deploy`
+/// and `info_get_transaction` responses are accepted, but only legacy deploys can be parsed
+/// – `TransactionV1` is rejected.
+pub(crate) fn deploy_from_json(content: &str) -> Result<Deploy, String> {
+    let value = serde_json::from_str(content).map_err(|err| err.to_string())?;
+    deploy_from_value(value)
+}
+
+/// Same as [`deploy_from_json`], for the already parsed JSON.
+pub(crate) fn deploy_from_value(mut value: serde_json::Value) -> Result<Deploy, String> {
+    // Positional parameters of the request hold just the transaction.
+    if let Some(params) = value.get_mut("params").and_then(|params| params.get_mut(0)) {
+        value = params.take();
+    }
+    for key in ["params", "result", "transaction", "Deploy", "deploy"] {
+        if value.get(key).is_some() {
+            value = value[key].take();
+        }
+    }
+    if value.get("Version1").is_some() {
+        return Err("TransactionV1 is not supported by this generator".to_string());
+    }
+    serde_json::from_value(value).map_err(|err| err.to_string())
+}
+
+/// Parses a deploy from its hex-encoded `bytesrepr` serialization – the exact bytes sent to the Ledger.
+pub(crate) fn deploy_from_hex(content: &str) -> Result<Deploy, String> {
+    let content = content.trim();
+    let content = content.strip_prefix("0x").unwrap_or(content);
+    let bytes = hex::decode(content).map_err(|err| format!("invalid hex: {}", err))?;
+    bytesrepr::deserialize(bytes).map_err(|err| format!("invalid deploy bytes: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deploy_from_hex, deploy_from_json};
+
+    #[test]
+    fn rejects_transaction_v1() {
+        let response = r#"{"result": {"transaction": {"Version1": {}}}}"#;
+        let err = deploy_from_json(response).unwrap_err();
+        assert!(err.contains("TransactionV1"));
+
+        let request = r#"{"jsonrpc": "2.0", "id": 1, "method": "account_put_transaction",
+            "params": {"transaction": {"Version1": {}}}}"#;
+        let err = deploy_from_json(request).unwrap_err();
+        assert!(err.contains("TransactionV1"));
+    }
+
+    #[test]
+    fn rejects_truncated_deploy_bytes() {
+        assert!(deploy_from_hex("0x0202531fe6").is_err());
+        assert!(deploy_from_hex("not hex").is_err());
+    }
+}
@@ -1,18 +1,33 @@
-use std::{fmt::Display, rc::Rc};
+use std::{borrow::Cow, fmt::Display, rc::Rc, sync::Arc};
 
+use casper_hashing::Digest;
 use casper_node::types::Deploy;
-use casper_types::bytesrepr::ToBytes;
+use casper_types::{bytesrepr::ToBytes, U512};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{message::CasperMessage, parser, sample::Sample};
+use crate::{
+    aliases::LabelAliases, checksummed_hex, currency::Currencies, error::ParseError,
+    message::CasperMessage, parser, policy::Policy, recognizer::DeployRecognizer,
+    redaction::Redacted, registry::Registry, sample::Sample, Options,
+};
+
+/// Version of the test vectors' format.
+/// Bump it whenever the structure or the meaning of the emitted fields changes.
+pub(crate) const SCHEMA_VERSION: u32 = 2;
+
+/// Number of pages after which the Ledger suggests switching to the expert mode.
+pub(crate) const DEFAULT_PAGE_LIMIT: u8 = 15;
+
+/// Label of the warnings, by which the outputs tell their pages apart.
+const WARNING_LABEL: &str = "Warning";
 
 // Character limit for Ledger's "label" row.
-const LEDGER_VIEW_NAME_CHAR_COUNT: usize = 11;
+pub(crate) const LEDGER_VIEW_NAME_CHAR_COUNT: usize = 11;
 // Character limit for Ledger's value top row.
-const LEDGER_VIEW_TOP_ROW_CHAR_COUNT: usize = 17;
+pub(crate) const LEDGER_VIEW_TOP_ROW_CHAR_COUNT: usize = 17;
 // Character limit for Ledger's value bottom row.
-const LEDGER_VIEW_BOTTOM_CHAR_COUNT: usize = 17;
+pub(crate) const LEDGER_VIEW_BOTTOM_CHAR_COUNT: usize = 17;
 
 #[derive(Clone, Copy)]
 pub(crate) enum TxnPhase {
@@ -24,53 +39,145 @@ impl TxnPhase {
     pub(crate) fn is_payment(&self) -> bool {
         matches!(self, TxnPhase::Payment)
     }
+
+    /// Label of the element naming the phase's item.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            TxnPhase::Payment => "Payment",
+            TxnPhase::Session => "Execution",
+        }
+    }
 }
 
 impl Display for TxnPhase {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TxnPhase::Payment => write!(f, "Payment"),
-            TxnPhase::Session => write!(f, "Execution"),
-        }
+        write!(f, "{}", self.label())
     }
 }
 
 /// A single element of the transaction to be displayed in Ledger.
-#[derive(Debug, Clone)]
-pub(crate) struct Element {
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Element {
     /// Label of the element to display - like `from`, `to`, `amount`.
-    name: String,
+    /// Constant labels are borrowed, so that they aren't allocated for every element.
+    #[serde(rename = "label")]
+    name: Cow<'static, str>,
     /// Value of the element.
     value: String,
     // Whether to display in expert mode only.
     expert: bool,
+    // Whether to emit for the debugging tools only – never displayed by the device.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    debug: bool,
+    // Whether the value is displayed raw, as it couldn't be decoded.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    undecoded: bool,
+    // Whether the element warns about a risk of the deploy, on a screen of its own.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    warning: bool,
+    // Section of the deploy the element belongs to, when the elements are grouped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    section: Option<Section>,
+}
+
+/// Tier of the elements, from the ones displayed in regular mode
+/// to the low-level ones emitted for the debugging tools only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tier {
+    /// Displayed in regular and expert modes.
+    Regular,
+    /// Displayed in expert mode only.
+    Expert,
+    /// Emitted on request only, like the serialized lengths – the device never displays them.
+    Debug,
 }
 
-// Capitalizes the first character.
-fn capitalize_first(s: &str) -> String {
+/// Section of the deploy an element belongs to, for the displays grouping the elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Section {
+    /// Hashes, type and header of the deploy.
+    Header,
+    /// Kinds and addresses of the payment and session items, with the called entry points.
+    Execution,
+    /// Arguments of the payment and session items.
+    Arguments,
+    /// Signers of the deploy.
+    Approvals,
+}
+
+/// Whether the printed label is of a warning – the labels of pages
+/// of the warnings spanning several ones are followed by the counter, like `Warning [1/2]`.
+pub(crate) fn is_warning_label(label: &str) -> bool {
+    label.split(" [").next() == Some(WARNING_LABEL)
+}
+
+// Characters the device can display.
+pub(crate) fn is_printable(s: &str) -> bool {
+    s.chars().all(|c| (' '..='~').contains(&c))
+}
+
+// Capitalizes the first character, allocating only when it isn't capitalized already.
+fn capitalize_first(s: Cow<'static, str>) -> Cow<'static, str> {
     let mut c = s.chars();
     match c.next() {
-        None => String::new(),
-        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+        Some(f) if f.is_lowercase() => {
+            Cow::Owned(f.to_uppercase().collect::<String>() + c.as_str())
+        }
+        _ => s,
     }
 }
 
 impl Element {
     /// Creates an instance of the element, marking it as to be displayed in expert-only mode.
-    pub(crate) fn expert(name: &str, value: String) -> Element {
+    pub fn expert(name: impl Into<Cow<'static, str>>, value: String) -> Element {
+        Element {
+            name: capitalize_first(name.into()),
+            value,
+            expert: true,
+            debug: false,
+            undecoded: false,
+            warning: false,
+            section: None,
+        }
+    }
+
+    /// Creates an instance of the element of the debug tier, emitted on request only.
+    pub fn debug(name: impl Into<Cow<'static, str>>, value: String) -> Element {
         Element {
-            name: capitalize_first(name),
+            name: capitalize_first(name.into()),
             value,
             expert: true,
+            debug: true,
+            undecoded: false,
+            warning: false,
+            section: None,
         }
     }
 
     /// Creates an instance of the element, marking it as to be displayed in regular mode.
-    pub(crate) fn regular(name: &str, value: String) -> Self {
+    pub fn regular(name: impl Into<Cow<'static, str>>, value: String) -> Self {
+        Element {
+            name: capitalize_first(name.into()),
+            value,
+            expert: false,
+            debug: false,
+            undecoded: false,
+            warning: false,
+            section: None,
+        }
+    }
+
+    /// Creates a warning about a risk of the deploy, displayed in regular mode
+    /// on the dedicated warning screen.
+    pub fn warning(value: String) -> Self {
         Element {
-            name: capitalize_first(name),
+            name: Cow::Borrowed(WARNING_LABEL),
             value,
             expert: false,
+            debug: false,
+            undecoded: false,
+            warning: true,
+            section: None,
         }
     }
 
@@ -78,6 +185,83 @@ impl Element {
     pub(crate) fn as_expert(&mut self) {
         self.expert = true;
     }
+
+    /// Replaces the value of the element.
+    pub(crate) fn with_value(mut self, value: String) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Replaces the label of the element, as is.
+    pub(crate) fn relabelled(mut self, label: String) -> Self {
+        self.name = Cow::Owned(label);
+        self
+    }
+
+    /// Tags the element with the section of the deploy it belongs to.
+    pub(crate) fn in_section(mut self, section: Section) -> Self {
+        self.section = Some(section);
+        self
+    }
+
+    /// Marks the value as displayed raw, because it couldn't be decoded.
+    pub(crate) fn as_undecoded(mut self) -> Self {
+        self.undecoded = true;
+        self
+    }
+
+    /// Fails when the device can't display the element as is.
+    pub(crate) fn check_displayable(&self) -> Result<(), ParseError> {
+        let reason = if self.undecoded {
+            "value couldn't be decoded"
+        } else if self.name.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT {
+            "label is too long"
+        } else if !is_printable(&self.name) || !is_printable(&self.value) {
+            "contains non-printable characters"
+        } else {
+            return Ok(());
+        };
+        Err(ParseError::NotDisplayable {
+            label: self.name.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    /// Label of the element – like `From`, `To`, `Amount`.
+    pub fn label(&self) -> &str {
+        &self.name
+    }
+
+    /// Value of the element, before it's split into pages.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Whether the element warns about a risk of the deploy.
+    pub fn is_warning(&self) -> bool {
+        self.warning
+    }
+
+    /// Whether the element is displayed in expert mode only – or not at all, in the debug tier.
+    pub fn is_expert(&self) -> bool {
+        self.expert
+    }
+
+    /// Section of the deploy the element belongs to, if the elements are grouped.
+    pub fn section(&self) -> Option<Section> {
+        self.section
+    }
+
+    /// Tier of the element.
+    pub fn tier(&self) -> Tier {
+        if self.debug {
+            Tier::Debug
+        } else if self.expert {
+            Tier::Expert
+        } else {
+            Tier::Regular
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -87,10 +271,16 @@ struct Ledger {
 }
 
 impl Ledger {
-    fn from_deploy(deploy: Deploy) -> Self {
-        Ledger {
-            ledger_elements: parser::parse_deploy(deploy),
-        }
+    fn from_deploy(deploy: &Deploy, options: &Options) -> Result<Self, ParseError> {
+        Self::from_elements(parser::ElementStream::new(deploy, options))
+    }
+
+    fn from_elements(
+        elements: impl Iterator<Item = Result<Element, ParseError>>,
+    ) -> Result<Self, ParseError> {
+        Ok(Ledger {
+            ledger_elements: elements.collect::<Result<_, _>>()?,
+        })
     }
 
     fn from_message(casper_message: CasperMessage) -> Self {
@@ -151,9 +341,11 @@ impl std::fmt::Display for LedgerValue {
 #[derive(Default, Clone)]
 struct LedgerPageView {
     // Name of the panel, like hash, chain name, sender, etc.
-    name: String,
+    name: Cow<'static, str>,
     // Whether element is for expert mode only.
     expert: bool,
+    // Section of the deploy the element belongs to, if grouped.
+    section: Option<Section>,
     values: Vec<LedgerValue>,
 }
 
@@ -183,8 +375,9 @@ impl LedgerPageView {
         values.push(curr_value);
 
         LedgerPageView {
-            name: element.name.clone(),
+            name: element.name,
             expert: element.expert,
+            section: element.section,
             values,
         }
     }
@@ -250,6 +443,72 @@ impl LedgerView {
         }
         output
     }
+
+    // Runs of consecutive pages of the same section, in the order of display –
+    // none unless every element is tagged with its section.
+    fn sections(&self, expert: bool) -> Vec<SectionSpan> {
+        let mut spans: Vec<SectionSpan> = vec![];
+        for page in self.pages.iter().filter(|page| expert || !page.expert) {
+            let section = match page.section {
+                Some(section) => section,
+                None => return vec![],
+            };
+            match spans.last_mut() {
+                Some(span) if span.section == section => span.pages += page.values.len(),
+                _ => spans.push(SectionSpan {
+                    section,
+                    pages: page.values.len(),
+                }),
+            }
+        }
+        spans
+    }
+}
+
+/// Run of consecutive pages of the same section of the deploy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SectionSpan {
+    pub(crate) section: Section,
+    /// Number of pages of the run.
+    pub(crate) pages: usize,
+}
+
+/// Element reassembled from the printed Ledger pages – the inverse of `LedgerView::to_string`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct DisplayedElement {
+    pub(crate) label: String,
+    pub(crate) value: String,
+    /// Number of pages the element spans.
+    pub(crate) page_count: usize,
+}
+
+/// Groups printed pages (like `"1 | To [2/2] : 0101"`) back into the displayed elements.
+pub(crate) fn displayed_elements(pages: &[String]) -> Vec<DisplayedElement> {
+    let mut elements: Vec<(String, DisplayedElement)> = vec![];
+    for page in pages {
+        let (idx, page) = page.split_once(" | ").unwrap_or(("", page));
+        let (label, value) = page.split_once(" : ").unwrap_or((page, ""));
+        // Drop the page counter (` [1/2]`) of the elements spanning multiple pages.
+        let label = match label.rsplit_once(" [") {
+            Some((label, counter)) if counter.ends_with(']') => label,
+            _ => label,
+        };
+        match elements.last_mut() {
+            Some((last_idx, element)) if last_idx == idx => {
+                element.value.push_str(value);
+                element.page_count += 1;
+            }
+            _ => elements.push((
+                idx.to_string(),
+                DisplayedElement {
+                    label: label.to_string(),
+                    value: value.to_string(),
+                    page_count: 1,
+                },
+            )),
+        }
+    }
+    elements.into_iter().map(|(_, element)| element).collect()
 }
 
 type LedgerCallback = Rc<dyn Fn(&Ledger) -> Vec<String>>;
@@ -261,6 +520,30 @@ pub(crate) struct LimitedLedgerConfig {
     page_limit: u8,
     on_regular: LedgerCallback,
     on_expert: LedgerCallback,
+    // Known contracts – for previews only, the device doesn't know them.
+    registry: Option<Arc<Registry>>,
+    // Decoders of other contracts – for previews only too.
+    recognizers: Vec<Arc<dyn DeployRecognizer>>,
+    // Whether to display the validity of the approvals, which the device doesn't check.
+    verify_signatures: bool,
+    // Visibility of the arguments set by the integrator – for previews only too.
+    policy: Option<Arc<Policy>>,
+    // Number of pages above which values are truncated, as the device's memory allows.
+    element_page_limit: Option<u8>,
+    // Labels displayed instead of the built-in ones.
+    label_aliases: Option<Arc<LabelAliases>>,
+    // Amount (in motes) above which the items are warned about.
+    amount_warning_threshold: Option<U512>,
+    // Whether to display the digests of the values instead of them.
+    redact: bool,
+    // Currencies of the amounts, by the name of the chain.
+    currencies: Option<Arc<Currencies>>,
+    // Offset (in minutes east of UTC) of the local time displayed after the timestamp.
+    timezone_offset: Option<i32>,
+    // Whether to add the elements of the debug tier to the expert pages.
+    debug: bool,
+    // Whether to record the sections of the deploy the pages belong to.
+    sections: bool,
 }
 
 impl LimitedLedgerConfig {
@@ -269,9 +552,95 @@ impl LimitedLedgerConfig {
             page_limit,
             on_regular: Rc::new(Self::deploy_complexity_notice),
             on_expert: Rc::new(Self::deploy_basic_info),
+            registry: None,
+            recognizers: vec![],
+            verify_signatures: false,
+            policy: None,
+            element_page_limit: None,
+            label_aliases: None,
+            amount_warning_threshold: None,
+            redact: false,
+            currencies: None,
+            timezone_offset: None,
+            debug: false,
+            sections: false,
         }
     }
 
+    /// Displays calls of the contracts known to the `registry` with their names.
+    pub(crate) fn with_registry(mut self, registry: Arc<Registry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Decodes the calls recognized by the `recognizer`, before the built-in decoders.
+    pub(crate) fn with_recognizer(mut self, recognizer: Arc<dyn DeployRecognizer>) -> Self {
+        self.recognizers.push(recognizer);
+        self
+    }
+
+    /// Displays (or hides) the arguments as the `policy` says.
+    pub(crate) fn with_policy(mut self, policy: Arc<Policy>) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Truncates the values spanning more than `limit` pages, moving the full value
+    /// to an expert element that follows.
+    pub(crate) fn with_element_page_limit(mut self, limit: u8) -> Self {
+        self.element_page_limit = Some(limit);
+        self
+    }
+
+    /// Displays the elements with the aliases of their labels.
+    pub(crate) fn with_label_aliases(mut self, aliases: Arc<LabelAliases>) -> Self {
+        self.label_aliases = Some(aliases);
+        self
+    }
+
+    /// Warns about the amounts above the `threshold` of motes.
+    pub(crate) fn with_amount_warning_threshold(mut self, threshold: U512) -> Self {
+        self.amount_warning_threshold = Some(threshold);
+        self
+    }
+
+    /// Replaces the values of the elements by their digests.
+    pub(crate) fn with_redaction(mut self) -> Self {
+        self.redact = true;
+        self
+    }
+
+    /// Displays the amounts in the currencies of the chains.
+    pub(crate) fn with_currencies(mut self, currencies: Arc<Currencies>) -> Self {
+        self.currencies = Some(currencies);
+        self
+    }
+
+    /// Displays the local time of the timestamp too, `offset_minutes` east of UTC.
+    pub(crate) fn with_timezone_offset(mut self, offset_minutes: i32) -> Self {
+        self.timezone_offset = Some(offset_minutes);
+        self
+    }
+
+    /// Adds the elements of the debug tier to the expert pages, for comparing the vectors
+    /// at the level of the serialized deploy – the device never displays them.
+    pub(crate) fn with_debug_elements(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    /// Records the sections of the deploy the pages belong to, along with the pages.
+    pub(crate) fn with_sections(mut self) -> Self {
+        self.sections = true;
+        self
+    }
+
+    /// Verifies the signatures of the approvals.
+    pub(crate) fn with_signature_verification(mut self) -> Self {
+        self.verify_signatures = true;
+        self
+    }
+
     fn deploy_complexity_notice(_ledger: &Ledger) -> Vec<String> {
         todo!()
     }
@@ -288,6 +657,15 @@ struct LimitedLedgerView<'a> {
 
 impl<'a> LimitedLedgerView<'a> {
     fn new(config: &'a LimitedLedgerConfig, ledger: Ledger) -> Self {
+        let ledger = match config.element_page_limit {
+            Some(limit) => Ledger {
+                ledger_elements: ledger
+                    .into_ledger_elements()
+                    .flat_map(|element| truncated(element, limit))
+                    .collect(),
+            },
+            None => ledger,
+        };
         Self {
             _config: config,
             ledger,
@@ -301,12 +679,56 @@ impl<'a> LimitedLedgerView<'a> {
     fn expert(&self) -> Vec<String> {
         LedgerView::from_ledger(self.ledger.clone()).to_string(true)
     }
+
+    fn sections(&self, expert: bool) -> Vec<SectionSpan> {
+        LedgerView::from_ledger(self.ledger.clone()).sections(expert)
+    }
+}
+
+/// Cuts the value of the element down to `page_limit` pages, the last one ending with
+/// a `...(+N more)` marker of the pages left out – followed by the full value in expert mode.
+///
+/// The marker is ASCII, as the device can't display an ellipsis.
+fn truncated(element: Element, page_limit: u8) -> Vec<Element> {
+    let page_char_count = LEDGER_VIEW_TOP_ROW_CHAR_COUNT + LEDGER_VIEW_BOTTOM_CHAR_COUNT;
+    let page_limit = usize::from(page_limit.max(1));
+    let page_count = (element.value.chars().count() + page_char_count - 1) / page_char_count;
+    if page_count <= page_limit {
+        return vec![element];
+    }
+    let marker = format!("...(+{} more)", page_count - page_limit);
+    let kept_char_count = page_limit * page_char_count - marker.len();
+    let value = element
+        .value
+        .chars()
+        .take(kept_char_count)
+        .collect::<String>()
+        + &marker;
+    let truncated = Element {
+        name: element.name.clone(),
+        value,
+        expert: element.expert,
+        debug: element.debug,
+        undecoded: element.undecoded,
+        warning: element.warning,
+        section: element.section,
+    };
+    vec![
+        truncated,
+        Element {
+            expert: true,
+            ..element
+        },
+    ]
 }
 
 /// Representation of a test vector that is structures in the way that Zondax's pipelines expect it.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(super) struct ZondaxRepr {
+    schema_version: u32,
     index: usize,
+    /// Identifier of the sample that stays the same when other samples are added or removed.
+    id: String,
     name: String,
     valid_regular: bool,
     valid_expert: bool,
@@ -314,34 +736,236 @@ pub(super) struct ZondaxRepr {
     blob: String,
     output: Vec<String>,
     output_expert: Vec<String>,
+    /// Sections of the deploy the pages of either mode belong to, in runs of consecutive pages –
+    /// when requested.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    output_sections: Vec<SectionSpan>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    output_expert_sections: Vec<SectionSpan>,
+    /// Whether the device signs the deploy blindly, displaying its hash only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    blind_signing: bool,
+}
+
+impl ZondaxRepr {
+    /// Drops pages of the display modes that weren't requested.
+    pub(crate) fn retain_modes(mut self, regular: bool, expert: bool) -> Self {
+        if !regular {
+            self.output.clear();
+            self.output_sections.clear();
+        }
+        if !expert {
+            self.output_expert.clear();
+            self.output_expert_sections.clear();
+        }
+        self
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn valid_regular(&self) -> bool {
+        self.valid_regular
+    }
+
+    pub(crate) fn valid_expert(&self) -> bool {
+        self.valid_expert
+    }
+
+    /// Returns hex-encoded, serialized sample.
+    pub(crate) fn blob(&self) -> &str {
+        &self.blob
+    }
+
+    /// Returns pages displayed in regular mode.
+    pub(crate) fn output(&self) -> &[String] {
+        &self.output
+    }
+
+    /// Returns pages displayed in expert mode.
+    pub(crate) fn output_expert(&self) -> &[String] {
+        &self.output_expert
+    }
+
+    /// Returns the sections of the pages displayed in regular mode, if recorded.
+    pub(crate) fn output_sections(&self) -> &[SectionSpan] {
+        &self.output_sections
+    }
+
+    /// Returns the sections of the pages displayed in expert mode, if recorded.
+    pub(crate) fn output_expert_sections(&self) -> &[SectionSpan] {
+        &self.output_expert_sections
+    }
+
+    /// Returns `true` when some of the elements are displayed only in expert mode.
+    pub(crate) fn has_expert_only_elements(&self) -> bool {
+        self.output != self.output_expert
+    }
+}
+
+/// Parses previously generated test vectors.
+/// Vectors of an unknown schema version are rejected, as their fields can't be trusted.
+pub(crate) fn parse_vectors(json: &str) -> Result<Vec<ZondaxRepr>, String> {
+    let vectors: Vec<ZondaxRepr> =
+        serde_json::from_str(json).map_err(|err| format!("invalid test vectors: {}", err))?;
+    match vectors
+        .iter()
+        .find(|vector| vector.schema_version != SCHEMA_VERSION)
+    {
+        Some(vector) => Err(format!(
+            "test vector {} has unsupported schema version {} (expected {}), regenerate the vectors",
+            vector.index, vector.schema_version, SCHEMA_VERSION
+        )),
+        None => Ok(vectors),
+    }
+}
+
+/// Returns the view of the pages displayed by the Ledger for the deploy.
+fn deploy_view<'a>(
+    deploy: &Deploy,
+    config: &'a LimitedLedgerConfig,
+) -> Result<LimitedLedgerView<'a>, ParseError> {
+    let mut options = Options::default()
+        .expert(true)
+        .debug(config.debug)
+        .sections(config.sections)
+        .verify_signatures(config.verify_signatures);
+    if let Some(registry) = &config.registry {
+        options = options.registry(Arc::clone(registry));
+    }
+    for recognizer in &config.recognizers {
+        options = options.recognizer(Arc::clone(recognizer));
+    }
+    if let Some(policy) = &config.policy {
+        options = options.policy(Arc::clone(policy));
+    }
+    if let Some(aliases) = &config.label_aliases {
+        options = options.label_aliases(Arc::clone(aliases));
+    }
+    if let Some(threshold) = config.amount_warning_threshold {
+        options = options.amount_warning_threshold(threshold);
+    }
+    if let Some(currencies) = &config.currencies {
+        options = options.currencies(Arc::clone(currencies));
+    }
+    if let Some(offset) = config.timezone_offset {
+        options = options.timezone_offset(offset);
+    }
+    let ledger = if config.redact {
+        Ledger::from_elements(Redacted::new(parser::ElementStream::new(deploy, &options)))?
+    } else {
+        Ledger::from_deploy(deploy, &options)?
+    };
+    Ok(LimitedLedgerView::new(config, ledger))
 }
 
 /// Maps `Deploy` structure to the expected JSON representation.
+///
+/// The deploy is borrowed, so that deploys with large module bytes aren't copied along the way.
 pub(super) fn deploy_to_json(
     index: usize,
-    sample_deploy: Sample<Deploy>,
+    id: String,
+    sample_deploy: &Sample<Deploy>,
     config: &LimitedLedgerConfig,
+) -> Result<ZondaxRepr, ParseError> {
+    let ledger_view = deploy_view(sample_deploy.sample(), config)?;
+    deploy_repr(index, id, sample_deploy, &ledger_view, false)
+}
+
+/// Maps a deploy the device can't display to the vector of its blind signing,
+/// where only the hash of the deploy is displayed.
+pub(super) fn deploy_to_blind_json(
+    index: usize,
+    id: String,
+    sample_deploy: &Sample<Deploy>,
+    config: &LimitedLedgerConfig,
+) -> Result<ZondaxRepr, ParseError> {
+    let ledger = Ledger {
+        ledger_elements: vec![Element::regular(
+            "Txn hash",
+            checksummed_hex::encode(sample_deploy.sample().hash().inner()),
+        )],
+    };
+    let ledger_view = LimitedLedgerView::new(config, ledger);
+    deploy_repr(index, id, sample_deploy, &ledger_view, true)
+}
+
+/// Maps a deploy with module bytes above the size limit to the vector describing them
+/// by their length and hash – the deploy is neither serialized nor rendered, so the vector has no blob.
+pub(super) fn deploy_to_oversized_json(
+    index: usize,
+    id: String,
+    sample_deploy: &Sample<Deploy>,
+    config: &LimitedLedgerConfig,
+    module_bytes_len: usize,
+    module_bytes_hash: Digest,
 ) -> ZondaxRepr {
-    let (name, deploy, valid) = sample_deploy.destructure();
-    let blob = hex::encode(deploy.to_bytes().unwrap());
-    let ledger = Ledger::from_deploy(deploy);
+    let ledger = Ledger {
+        ledger_elements: vec![
+            Element::regular("Module size", format!("{} bytes", module_bytes_len)),
+            Element::regular("Module hash", format!("{:?}", module_bytes_hash)),
+        ],
+    };
     let ledger_view = LimitedLedgerView::new(config, ledger);
-    let output = ledger_view.regular();
-    let output_expert = ledger_view.expert();
+    let valid = sample_deploy.is_valid();
     ZondaxRepr {
+        schema_version: SCHEMA_VERSION,
         index,
-        name,
+        id,
+        name: sample_deploy.label().to_string(),
         valid_regular: valid,
         valid_expert: valid,
         testnet: true,
-        blob,
-        output,
-        output_expert,
+        blob: String::new(),
+        output: ledger_view.regular(),
+        output_expert: ledger_view.expert(),
+        output_sections: vec![],
+        output_expert_sections: vec![],
+        blind_signing: false,
     }
 }
 
+fn deploy_repr(
+    index: usize,
+    id: String,
+    sample_deploy: &Sample<Deploy>,
+    ledger_view: &LimitedLedgerView,
+    blind_signing: bool,
+) -> Result<ZondaxRepr, ParseError> {
+    let valid = sample_deploy.is_valid();
+    let blob = sample_deploy.sample().to_bytes().map_err(|err| {
+        ParseError::InvalidValue(format!("failed to serialize the deploy: {}", err))
+    })?;
+    let blob = hex::encode(blob);
+    Ok(ZondaxRepr {
+        schema_version: SCHEMA_VERSION,
+        index,
+        id,
+        name: sample_deploy.label().to_string(),
+        valid_regular: valid,
+        valid_expert: valid,
+        testnet: true,
+        blob,
+        output: ledger_view.regular(),
+        output_expert: ledger_view.expert(),
+        output_sections: ledger_view.sections(false),
+        output_expert_sections: ledger_view.sections(true),
+        blind_signing,
+    })
+}
+
 pub(super) fn message_to_json(
     index: usize,
+    id: String,
     sample_msg: Sample<CasperMessage>,
     config: &LimitedLedgerConfig,
 ) -> ZondaxRepr {
@@ -355,7 +979,9 @@ pub(super) fn message_to_json(
     let output_expert = ledger_view.expert();
 
     ZondaxRepr {
+        schema_version: SCHEMA_VERSION,
         index,
+        id,
         name,
         valid_regular: valid,
         valid_expert: valid,
@@ -363,5 +989,165 @@ pub(super) fn message_to_json(
         blob,
         output,
         output_expert,
+        output_sections: vec![],
+        output_expert_sections: vec![],
+        blind_signing: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::testing::TestRng;
+
+    use crate::{deploy_to_elements, parser, sample::StableIds, test_data, Options};
+
+    use super::{
+        deploy_to_json, displayed_elements, parse_vectors, truncated, DisplayedElement, Element,
+        LedgerPageView, LimitedLedgerConfig, Section, SectionSpan, Tier, DEFAULT_PAGE_LIMIT,
+        SCHEMA_VERSION,
+    };
+
+    // Both the elements and the test vectors are read back by the tooling,
+    // so their JSON form must not lose anything.
+    #[test]
+    fn corpus_round_trips_through_json() {
+        let mut rng = TestRng::new();
+        let mut stable_ids = StableIds::default();
+        let config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+        let mut elements = vec![];
+        let mut vectors = vec![];
+        for (index, sample) in test_data::deploy_samples(&mut rng).into_iter().enumerate() {
+            let id = stable_ids.assign(sample.label());
+            elements.extend(parser::parse_deploy(sample.sample()).unwrap());
+            vectors.push(deploy_to_json(index, id, &sample, &config).unwrap());
+        }
+
+        let elements_json = serde_json::to_string(&elements).unwrap();
+        let parsed_elements: Vec<Element> = serde_json::from_str(&elements_json).unwrap();
+        assert_eq!(parsed_elements, elements);
+
+        let vectors_json = serde_json::to_string(&vectors).unwrap();
+        assert_eq!(parse_vectors(&vectors_json).unwrap(), vectors);
+    }
+
+    #[test]
+    fn debug_tier_is_emitted_on_request_only() {
+        let mut rng = TestRng::new();
+        let sample = test_data::deploy_samples(&mut rng).remove(0);
+        let tiers = |options: &Options| -> Vec<Tier> {
+            deploy_to_elements(sample.sample(), options)
+                .unwrap()
+                .iter()
+                .map(Element::tier)
+                .collect()
+        };
+        assert!(!tiers(&Options::default().expert(true)).contains(&Tier::Debug));
+        let debug = tiers(&Options::default().debug(true));
+        assert!(debug.contains(&Tier::Debug));
+        assert!(debug.contains(&Tier::Expert));
+    }
+
+    #[test]
+    fn sections_cover_every_page() {
+        let mut rng = TestRng::new();
+        let sample = test_data::deploy_samples(&mut rng).remove(0);
+        let config = LimitedLedgerConfig::new(DEFAULT_PAGE_LIMIT);
+        let flat = deploy_to_json(0, "flat".to_string(), &sample, &config).unwrap();
+        assert!(flat.output_expert_sections().is_empty());
+
+        let repr =
+            deploy_to_json(0, "grouped".to_string(), &sample, &config.with_sections()).unwrap();
+        let page_count = |spans: &[SectionSpan]| spans.iter().map(|span| span.pages).sum::<usize>();
+        assert_eq!(page_count(repr.output_sections()), repr.output().len());
+        let sections = repr.output_expert_sections();
+        assert_eq!(page_count(sections), repr.output_expert().len());
+        assert_eq!(sections.first().unwrap().section, Section::Header);
+        assert_eq!(sections.last().unwrap().section, Section::Approvals);
+    }
+
+    #[test]
+    fn undisplayable_elements() {
+        assert!(Element::regular("amount", "10 motes".to_string())
+            .check_displayable()
+            .is_ok());
+        assert!(Element::regular("amount", "10".to_string())
+            .as_undecoded()
+            .check_displayable()
+            .is_err());
+        assert!(Element::regular("a very long label", "10".to_string())
+            .check_displayable()
+            .is_err());
+        assert!(Element::regular("name", "caf\u{e9}".to_string())
+            .check_displayable()
+            .is_err());
+    }
+
+    #[test]
+    fn long_value_spans_multiple_pages() {
+        // Single page fits 2 rows of 17 characters.
+        let value = "a".repeat(100);
+        let page_view = LedgerPageView::from_element(Element::regular("name", value));
+        let pages = page_view.to_string();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0], format!("Name [1/3] : {}", "a".repeat(34)));
+        assert_eq!(pages[1], format!("Name [2/3] : {}", "a".repeat(34)));
+        // Nothing is truncated.
+        assert_eq!(pages[2], format!("Name [3/3] : {}", "a".repeat(32)));
+    }
+
+    #[test]
+    fn long_value_is_truncated_to_page_limit() {
+        let elements = truncated(Element::regular("name", "a".repeat(100)), 2);
+        assert_eq!(elements.len(), 2);
+        assert_eq!(
+            elements[0].value(),
+            format!("{}...(+1 more)", "a".repeat(68 - 13))
+        );
+        assert!(!elements[0].is_expert());
+        let pages = LedgerPageView::from_element(elements[0].clone()).to_string();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(elements[1].value(), "a".repeat(100));
+        assert!(elements[1].is_expert());
+        assert_eq!(
+            truncated(Element::regular("name", "a".repeat(68)), 2).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn reassembles_elements_from_pages() {
+        let pages = vec![
+            "0 | Type : Transfer".to_string(),
+            "1 | To [1/2] : 0101".to_string(),
+            "1 | To [2/2] : 0202".to_string(),
+        ];
+        assert_eq!(
+            displayed_elements(&pages),
+            vec![
+                DisplayedElement {
+                    label: "Type".to_string(),
+                    value: "Transfer".to_string(),
+                    page_count: 1,
+                },
+                DisplayedElement {
+                    label: "To".to_string(),
+                    value: "01010202".to_string(),
+                    page_count: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_schema_version() {
+        let vectors = |version: u32| {
+            format!(
+                r#"[{{"schema_version": {}, "index": 0, "id": "sample-0", "name": "sample", "valid_regular": true,
+                "valid_expert": true, "testnet": true, "blob": "", "output": [], "output_expert": []}}]"#,
+                version
+            )
+        };
+        assert!(parse_vectors(&vectors(SCHEMA_VERSION)).is_ok());
+        assert!(parse_vectors(&vectors(SCHEMA_VERSION + 1)).is_err());
     }
 }
@@ -0,0 +1,245 @@
+//! Rendering of Casper deploys into the elements displayed by the Ledger app,
+//! and the generator of the app's test vectors built on top of it.
+//!
+//! Wallets can use [`deploy_to_elements`] to show what the device will display
+//! for a deploy before asking the user to sign it.
+
+// Without the command line interface, the test vector generation isn't reachable.
+#![cfg_attr(not(feature = "cli"), allow(dead_code))]
+
+use std::sync::Arc;
+
+use casper_node::types::Deploy;
+use casper_types::U512;
+#[cfg(feature = "cli")]
+use clap::Parser;
+
+#[cfg(feature = "cli")]
+use cli::{Cli, Command};
+
+pub use abi::Abi;
+pub use aliases::LabelAliases;
+pub use currency::Currencies;
+pub use error::ParseError;
+pub use ledger::{Element, Section, Tier};
+pub use parser::ElementStream;
+pub use policy::Policy;
+pub use recognizer::DeployRecognizer;
+pub use redaction::Redacted;
+pub use registry::Registry;
+
+mod abi;
+mod aliases;
+pub mod apdu;
+pub mod checksummed_hex;
+#[cfg(feature = "cli")]
+mod cli;
+#[cfg(feature = "cli")]
+mod commands;
+#[cfg(feature = "cli")]
+mod config;
+#[cfg(test)]
+mod coverage;
+mod currency;
+pub mod error;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod fuzz;
+#[cfg(test)]
+mod goldens;
+mod import;
+mod ledger;
+mod manifest;
+mod message;
+mod output;
+mod parser;
+mod policy;
+mod recognizer;
+mod redaction;
+mod registry;
+#[cfg(feature = "cli")]
+mod rpc;
+mod sample;
+mod test_data;
+mod utils;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+/// Options of rendering the elements.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Options {
+    /// Whether to include the elements displayed only in expert mode.
+    pub expert: bool,
+    /// Whether to include the elements of the debug tier, which the device never displays.
+    pub debug: bool,
+    /// Whether to fail on elements the device can't display as is,
+    /// instead of rendering them on a best-effort basis.
+    pub strict: bool,
+    /// Known contracts, whose calls are displayed with their names.
+    pub registry: Option<Arc<Registry>>,
+    /// Decoders of other contracts, tried in the order of registration
+    /// before the built-in ones.
+    pub recognizers: Vec<Arc<dyn DeployRecognizer>>,
+    /// Whether to surface the arguments of unrecognized contract calls
+    /// that look like token transfers.
+    pub guess_token_transfers: bool,
+    /// Whether to verify the signatures of the approvals.
+    pub verify_signatures: bool,
+    /// Visibility of the arguments of entry points, set by the integrator.
+    pub policy: Option<Arc<Policy>>,
+    /// Labels displayed instead of the built-in ones.
+    pub label_aliases: Option<Arc<LabelAliases>>,
+    /// Amount (in motes) above which payments and transfers are warned about.
+    pub amount_warning_threshold: Option<U512>,
+    /// Currencies the amounts are displayed in, by the name of the chain.
+    pub currencies: Option<Arc<Currencies>>,
+    /// Offset (in minutes east of UTC) of the time zone the timestamp is displayed in too.
+    pub timezone_offset: Option<i32>,
+    /// Whether to tag the elements with the section of the deploy they belong to.
+    pub sections: bool,
+}
+
+impl Options {
+    /// Includes (or excludes) the elements displayed only in expert mode.
+    pub fn expert(mut self, expert: bool) -> Self {
+        self.expert = expert;
+        self
+    }
+
+    /// Includes (or excludes) the elements of the debug tier – low-level details
+    /// of the deploy, like the serialized lengths of its parts, for the fuzzing and diffing tools.
+    ///
+    /// The tier is below the expert mode, so the expert elements are included too.
+    /// The device never displays the debug ones, so they're left out of the expert mode otherwise.
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Fails (or not) on elements the device can't display as is –
+    /// with too long labels, non-printable characters or values that couldn't be decoded.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Displays calls of the contracts known to the `registry` with their names
+    /// and the labels of their arguments.
+    ///
+    /// The Ledger app doesn't know the registry, so this is meant for previews only –
+    /// the device itself shows the calls as generic ones.
+    pub fn registry(mut self, registry: Arc<Registry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Surfaces (or not) the `recipient` and `amount` arguments of unrecognized contract calls
+    /// as regular elements, after an "unverified contract" warning.
+    ///
+    /// The Ledger app makes no such guesses, so this is meant for previews only.
+    pub fn guess_token_transfers(mut self, guess: bool) -> Self {
+        self.guess_token_transfers = guess;
+        self
+    }
+
+    /// Verifies (or not) the signature of every approval against the deploy hash,
+    /// displaying whether it's valid in expert mode.
+    pub fn verify_signatures(mut self, verify: bool) -> Self {
+        self.verify_signatures = verify;
+        self
+    }
+
+    /// Registers a decoder of the payment and session items it recognizes.
+    ///
+    /// Like the registry, it's meant for previews only – the device doesn't know the decoder.
+    pub fn recognizer(mut self, recognizer: Arc<dyn DeployRecognizer>) -> Self {
+        self.recognizers.push(recognizer);
+        self
+    }
+
+    /// Displays the arguments listed in the `policy` in regular or expert mode,
+    /// or hides them – instead of displaying the digest of the arguments only.
+    ///
+    /// Like the registry, it's meant for previews only – the device doesn't know the policy.
+    pub fn policy(mut self, policy: Arc<Policy>) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Warns about the payment and session items with an `amount` above the `threshold` of motes.
+    pub fn amount_warning_threshold(mut self, threshold: U512) -> Self {
+        self.amount_warning_threshold = Some(threshold);
+        self
+    }
+
+    /// Displays the amounts of the chains listed in `currencies` in their currency,
+    /// instead of motes.
+    ///
+    /// The device displays motes, so this is meant for the vectors of other networks
+    /// (or denominations) only.
+    pub fn currencies(mut self, currencies: Arc<Currencies>) -> Self {
+        self.currencies = Some(currencies);
+        self
+    }
+
+    /// Displays the timestamp in the time zone `offset_minutes` east of UTC too,
+    /// after the one in UTC – as the explorers showing local times do.
+    pub fn timezone_offset(mut self, offset_minutes: i32) -> Self {
+        self.timezone_offset = Some(offset_minutes);
+        self
+    }
+
+    /// Tags (or not) every element with the section of the deploy it belongs to –
+    /// the header, the execution of the items, their arguments or the approvals –
+    /// so that the displays can group them into collapsible sections.
+    ///
+    /// The device displays a flat list, so the sections don't change what it shows.
+    pub fn sections(mut self, sections: bool) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    /// Displays the elements with the aliases of their labels, as they're built –
+    /// so that the wording can be changed (or an older one kept) without touching the parsers.
+    ///
+    /// The device displays the built-in labels, so the vectors must match its version.
+    pub fn label_aliases(mut self, aliases: Arc<LabelAliases>) -> Self {
+        self.label_aliases = Some(aliases);
+        self
+    }
+}
+
+/// Returns the elements the Ledger displays for the deploy, in the order of display.
+///
+/// The rendering holds no state, so it can be called from many threads at once.
+///
+/// Fails for deploys with arguments the Ledger can't display,
+/// and in strict mode for any element it can't display as is.
+pub fn deploy_to_elements(deploy: &Deploy, options: &Options) -> Result<Vec<Element>, ParseError> {
+    deploy_to_element_stream(deploy, options).collect()
+}
+
+/// Returns the elements of [`deploy_to_elements`] lazily, as they're parsed –
+/// so that the output for a huge deploy can be written before all of it is rendered.
+///
+/// The stream ends after the first error.
+pub fn deploy_to_element_stream<'a>(deploy: &'a Deploy, options: &Options) -> ElementStream<'a> {
+    ElementStream::new(deploy, options)
+}
+
+/// Runs the command line interface of the generator with the arguments of the process.
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub fn run_cli() -> Result<(), String> {
+    match Cli::parse().command {
+        Command::Generate(args) => commands::generate::run(&args),
+        Command::Parse(args) => commands::parse::run(&args),
+        Command::Diff(args) => commands::diff::run(&args),
+        Command::Validate(args) => commands::validate::run(&args),
+        Command::Watch(args) => commands::watch::run(&args),
+        Command::Sync(args) => commands::sync::run(&args),
+        Command::Bench(args) => commands::bench::run(&args),
+        Command::Attach(args) => commands::attach::run(&args),
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeSet;
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::PublicKey;
+use serde::Serialize;
+
+use crate::{
+    ledger::{ZondaxRepr, SCHEMA_VERSION},
+    parser::DeployKind,
+};
+
+/// Index of the generated test vectors,
+/// allowing test harnesses to select vectors without parsing all of them.
+#[derive(Serialize)]
+pub(crate) struct Manifest {
+    /// Version of the test vectors' format, the same as in the vectors themselves.
+    schema_version: u32,
+    entries: Vec<ManifestEntry>,
+}
+
+/// Description of a single generated test vector.
+#[derive(Serialize)]
+struct ManifestEntry {
+    /// Index of the vector in the generated collection.
+    index: usize,
+    /// Identifier of the vector that doesn't change between releases.
+    id: String,
+    name: String,
+    /// High-level kind of the sample – like `transfer`, `delegate` or `message`.
+    kind: String,
+    /// Entry point called by the session (if any).
+    entry_point: Option<String>,
+    /// Algorithms of the keys that signed the sample.
+    signer_algorithms: Vec<String>,
+    /// Whether the sample has elements displayed only in expert mode.
+    has_expert_only_elements: bool,
+    /// Length (in bytes) of the serialized sample.
+    blob_length: usize,
+}
+
+impl Manifest {
+    pub(crate) fn new() -> Self {
+        Manifest {
+            schema_version: SCHEMA_VERSION,
+            entries: vec![],
+        }
+    }
+
+    pub(crate) fn push_deploy(&mut self, deploy: &Deploy, repr: &ZondaxRepr) {
+        let signer_algorithms: BTreeSet<String> = deploy
+            .approvals()
+            .iter()
+            .map(|approval| key_algorithm(approval.signer()).to_string())
+            .collect();
+        self.entries.push(ManifestEntry::new(
+            repr,
+            DeployKind::of(deploy.session()).name(),
+            entry_point(deploy.session()),
+            signer_algorithms.into_iter().collect(),
+        ));
+    }
+
+    pub(crate) fn push_message(&mut self, repr: &ZondaxRepr) {
+        self.entries
+            .push(ManifestEntry::new(repr, "message", None, vec![]));
+    }
+}
+
+impl ManifestEntry {
+    fn new(
+        repr: &ZondaxRepr,
+        kind: &str,
+        entry_point: Option<String>,
+        signer_algorithms: Vec<String>,
+    ) -> Self {
+        ManifestEntry {
+            index: repr.index(),
+            id: repr.id().to_string(),
+            name: repr.name().to_string(),
+            kind: kind.to_string(),
+            entry_point,
+            signer_algorithms,
+            has_expert_only_elements: repr.has_expert_only_elements(),
+            blob_length: repr.blob().len() / 2,
+        }
+    }
+}
+
+fn entry_point(item: &ExecutableDeployItem) -> Option<String> {
+    match item {
+        ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => None,
+        ExecutableDeployItem::StoredContractByHash { entry_point, .. }
+        | ExecutableDeployItem::StoredContractByName { entry_point, .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { entry_point, .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { entry_point, .. } => {
+            Some(entry_point.clone())
+        }
+    }
+}
+
+fn key_algorithm(key: &PublicKey) -> &'static str {
+    match key {
+        PublicKey::Ed25519(_) => "ed25519",
+        PublicKey::Secp256k1(_) => "secp256k1",
+        PublicKey::System => "system",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use flate2::{write::GzEncoder, Compression};
+use serde::Deserialize;
+
+use crate::ledger::ZondaxRepr;
+
+pub(crate) mod apdus;
+pub(crate) mod blobs;
+pub(crate) mod c_header;
+pub(crate) mod csv;
+pub(crate) mod dir;
+pub(crate) mod html;
+pub(crate) mod json;
+pub(crate) mod markdown;
+pub(crate) mod ndjson;
+pub(crate) mod text;
+pub(crate) mod zemu;
+
+/// Format of the generated test vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(try_from = "String")]
+pub(crate) enum OutputFormat {
+    /// JSON structure consumed by the Zondax test pipelines.
+    #[default]
+    Json,
+    /// Header-only C fixture.
+    CHeader,
+    /// Markdown document for human review.
+    Markdown,
+    /// Static HTML page with simulated Ledger screens.
+    Html,
+    /// One JSON object per line, written as soon as the sample is generated.
+    Ndjson,
+    /// Pages of every sample as plain text.
+    Text,
+    /// One row per displayed element.
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "c-header" => Ok(OutputFormat::CHeader),
+            "markdown" => Ok(OutputFormat::Markdown),
+            "html" => Ok(OutputFormat::Html),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            "text" => Ok(OutputFormat::Text),
+            "csv" => Ok(OutputFormat::Csv),
+            other => Err(format!("unknown output format: {}", other)),
+        }
+    }
+}
+
+impl TryFrom<String> for OutputFormat {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl OutputFormat {
+    /// Extension of the file with vectors written in the format.
+    pub(crate) fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::CHeader => "h",
+            OutputFormat::Markdown => "md",
+            OutputFormat::Html => "html",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Text => "txt",
+            OutputFormat::Csv => "csv",
+        }
+    }
+
+    /// Returns the writer of the format.
+    fn writer(&self) -> Box<dyn OutputWriter> {
+        match self {
+            OutputFormat::Json => Box::new(Buffered::new(|out, data| json::write(out, data))),
+            OutputFormat::CHeader => {
+                Box::new(Buffered::new(|out, data| c_header::write(out, data)))
+            }
+            OutputFormat::Markdown => {
+                Box::new(Buffered::new(|out, data| markdown::write(out, data)))
+            }
+            OutputFormat::Html => Box::new(Buffered::new(|out, data| html::write(out, data))),
+            OutputFormat::Ndjson => Box::new(Streamed(|out, repr| ndjson::write(out, repr))),
+            OutputFormat::Text => Box::new(Streamed(|out, repr| text::write(out, repr))),
+            OutputFormat::Csv => Box::new(Buffered::new(|out, data| csv::write(out, data))),
+        }
+    }
+}
+
+/// Writer of the test vectors in one of the output formats.
+///
+/// Adding a format means implementing it and returning it from [`OutputFormat::writer`] –
+/// commands write the vectors through the [`VectorWriter`] only.
+trait OutputWriter {
+    /// Writes the vector, or keeps it until `finish` if the format needs all the vectors at once.
+    fn push(&mut self, out: &mut dyn Write, repr: ZondaxRepr) -> io::Result<()>;
+
+    /// Writes the vectors kept (if any), once all of them were pushed.
+    fn finish(&mut self, out: &mut dyn Write) -> io::Result<()>;
+}
+
+/// Format written as soon as a vector is generated.
+struct Streamed(fn(&mut dyn Write, &ZondaxRepr) -> io::Result<()>);
+
+impl OutputWriter for Streamed {
+    fn push(&mut self, out: &mut dyn Write, repr: ZondaxRepr) -> io::Result<()> {
+        (self.0)(out, &repr)
+    }
+
+    fn finish(&mut self, _out: &mut dyn Write) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Format that can be written only when all the vectors are known – like a JSON array.
+struct Buffered {
+    write: fn(&mut dyn Write, &[ZondaxRepr]) -> io::Result<()>,
+    vectors: Vec<ZondaxRepr>,
+}
+
+impl Buffered {
+    fn new(write: fn(&mut dyn Write, &[ZondaxRepr]) -> io::Result<()>) -> Self {
+        Buffered {
+            write,
+            vectors: vec![],
+        }
+    }
+}
+
+impl OutputWriter for Buffered {
+    fn push(&mut self, _out: &mut dyn Write, repr: ZondaxRepr) -> io::Result<()> {
+        self.vectors.push(repr);
+        Ok(())
+    }
+
+    fn finish(&mut self, out: &mut dyn Write) -> io::Result<()> {
+        (self.write)(out, &self.vectors)
+    }
+}
+
+/// Returns `true` for paths of gzip-compressed files.
+pub(crate) fn is_gzip(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "gz")
+}
+
+/// Destination of the written vectors.
+enum Output {
+    Plain(Box<dyn Write>),
+    /// Compressed file, whose stream has to be finished once all the vectors were written.
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl Output {
+    fn as_write(&mut self) -> &mut dyn Write {
+        match self {
+            Output::Plain(out) => out.as_mut(),
+            Output::Gzip(out) => out,
+        }
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Output::Plain(mut out) => out.flush(),
+            Output::Gzip(out) => out.finish()?.flush(),
+        }
+    }
+}
+
+/// Writes test vectors in the given format to the file at `path`,
+/// or to the standard output when the path is `-` or missing.
+///
+/// Files with the `.gz` extension are gzip-compressed.
+pub(crate) struct VectorWriter {
+    writer: Box<dyn OutputWriter>,
+    out: Output,
+}
+
+impl VectorWriter {
+    pub(crate) fn open(format: OutputFormat, path: Option<&Path>) -> io::Result<Self> {
+        let out = match path {
+            Some(path) if path != Path::new("-") => {
+                let file = BufWriter::new(File::create(path)?);
+                if is_gzip(path) {
+                    Output::Gzip(GzEncoder::new(file, Compression::default()))
+                } else {
+                    Output::Plain(Box::new(file))
+                }
+            }
+            _ => Output::Plain(Box::new(io::stdout().lock())),
+        };
+        Ok(VectorWriter {
+            writer: format.writer(),
+            out,
+        })
+    }
+
+    pub(crate) fn push(&mut self, repr: ZondaxRepr) -> io::Result<()> {
+        self.writer.push(self.out.as_write(), repr)
+    }
+
+    /// Writes the buffered vectors (if any) and flushes the output.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.writer.finish(self.out.as_write())?;
+        self.out.finish()
+    }
+}
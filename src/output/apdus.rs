@@ -0,0 +1,18 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    apdu::{self, DEFAULT_PATH},
+    ledger::ZondaxRepr,
+};
+
+/// Writes the APDUs signing the sample to `<dir>/<id>.apdus`, one hex-encoded APDU per line,
+/// prefixed with `=> ` – the format of APDU transcripts replayed by the Ledger tools.
+pub(crate) fn write_transcript(dir: &Path, repr: &ZondaxRepr) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let blob = hex::decode(repr.blob()).expect("blob to be hex-encoded");
+    let transcript: String = apdu::sign_apdus(&DEFAULT_PATH, &blob)
+        .iter()
+        .map(|apdu| format!("=> {}\n", hex::encode(apdu)))
+        .collect();
+    fs::write(dir.join(format!("{}.apdus", repr.id())), transcript)
+}
@@ -0,0 +1,28 @@
+use std::{fs, io, path::Path};
+
+use crate::ledger::ZondaxRepr;
+
+/// Writes a pair of files for the sample:
+/// `<dir>/<id>.bin` – the exact serialized sample, as sent to the device,
+/// `<dir>/<id>.expected.txt` – pages displayed in regular and expert modes.
+pub(crate) fn write_pair(dir: &Path, repr: &ZondaxRepr) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let name = repr.id();
+    let blob = hex::decode(repr.blob()).expect("blob to be hex-encoded");
+    fs::write(dir.join(format!("{}.bin", name)), blob)?;
+    fs::write(dir.join(format!("{}.expected.txt", name)), expected(repr))
+}
+
+fn expected(repr: &ZondaxRepr) -> String {
+    let mut expected = String::from("[regular]\n");
+    for page in repr.output() {
+        expected.push_str(page);
+        expected.push('\n');
+    }
+    expected.push_str("[expert]\n");
+    for page in repr.output_expert() {
+        expected.push_str(page);
+        expected.push('\n');
+    }
+    expected
+}
@@ -0,0 +1,135 @@
+use std::io::{self, Write};
+
+use crate::ledger::ZondaxRepr;
+
+// Number of blob bytes per line of the generated C array.
+const BYTES_PER_LINE: usize = 16;
+
+/// Writes test vectors as a header-only C fixture, so that the Ledger app's unit tests
+/// can include the generated vectors directly.
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, data: &[ZondaxRepr]) -> io::Result<()> {
+    writeln!(
+        writer,
+        "// Generated by casper-deploy-generator. DO NOT EDIT."
+    )?;
+    writeln!(writer, "#pragma once")?;
+    writeln!(writer)?;
+    writeln!(writer, "#include <stddef.h>")?;
+    writeln!(writer, "#include <stdint.h>")?;
+    writeln!(writer)?;
+    writeln!(writer, "typedef struct {{")?;
+    writeln!(writer, "    const char *name;")?;
+    writeln!(writer, "    uint8_t valid_regular;")?;
+    writeln!(writer, "    uint8_t valid_expert;")?;
+    writeln!(writer, "    const uint8_t *blob;")?;
+    writeln!(writer, "    size_t blob_len;")?;
+    writeln!(writer, "    const char *const *output;")?;
+    writeln!(writer, "    size_t output_len;")?;
+    writeln!(writer, "    const char *const *output_expert;")?;
+    writeln!(writer, "    size_t output_expert_len;")?;
+    writeln!(writer, "}} casper_test_vector_t;")?;
+
+    for repr in data {
+        let prefix = format!("vector_{}", repr.index());
+        let blob = hex::decode(repr.blob()).expect("blob to be hex-encoded");
+        writeln!(writer)?;
+        write_bytes(writer, &format!("{}_blob", prefix), &blob)?;
+        write_strings(writer, &format!("{}_output", prefix), repr.output())?;
+        write_strings(
+            writer,
+            &format!("{}_output_expert", prefix),
+            repr.output_expert(),
+        )?;
+    }
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "static const casper_test_vector_t casper_test_vectors[] = {{"
+    )?;
+    for repr in data {
+        let prefix = format!("vector_{}", repr.index());
+        writeln!(
+            writer,
+            "    {{\"{}\", {}, {}, {}_blob, {}, {}, {}, {}, {}}},",
+            escape(repr.name()),
+            repr.valid_regular() as u8,
+            repr.valid_expert() as u8,
+            prefix,
+            repr.blob().len() / 2,
+            array_or_null(&format!("{}_output", prefix), repr.output()),
+            repr.output().len(),
+            array_or_null(&format!("{}_output_expert", prefix), repr.output_expert()),
+            repr.output_expert().len(),
+        )?;
+    }
+    writeln!(writer, "}};")?;
+    writeln!(
+        writer,
+        "static const size_t casper_test_vectors_count = {};",
+        data.len()
+    )
+}
+
+fn write_bytes<W: Write + ?Sized>(writer: &mut W, name: &str, bytes: &[u8]) -> io::Result<()> {
+    writeln!(writer, "static const uint8_t {}[] = {{", name)?;
+    for chunk in bytes.chunks(BYTES_PER_LINE) {
+        let line: Vec<String> = chunk.iter().map(|byte| format!("0x{:02x}", byte)).collect();
+        writeln!(writer, "    {},", line.join(", "))?;
+    }
+    writeln!(writer, "}};")
+}
+
+fn write_strings<W: Write + ?Sized>(
+    writer: &mut W,
+    name: &str,
+    strings: &[String],
+) -> io::Result<()> {
+    // Empty arrays are not allowed in C, these are represented with `NULL` instead.
+    if strings.is_empty() {
+        return Ok(());
+    }
+    writeln!(writer, "static const char *const {}[] = {{", name)?;
+    for string in strings {
+        writeln!(writer, "    \"{}\",", escape(string))?;
+    }
+    writeln!(writer, "}};")
+}
+
+fn array_or_null(name: &str, strings: &[String]) -> String {
+    if strings.is_empty() {
+        "NULL".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Escapes the string for a C string literal.
+/// Bytes outside of printable ASCII are written as octal escapes, which (unlike hex ones)
+/// can't swallow the characters that follow them.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'"' => escaped.push_str("\\\""),
+            b'\\' => escaped.push_str("\\\\"),
+            // Avoids accidental trigraphs.
+            b'?' => escaped.push_str("\\?"),
+            0x20..=0x7e => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape;
+
+    #[test]
+    fn escapes_c_string_literal() {
+        assert_eq!(escape("Fee : 1 motes"), "Fee : 1 motes");
+        assert_eq!(escape("\"a\\b?\""), "\\\"a\\\\b\\?\\\"");
+        assert_eq!(escape("…"), "\\342\\200\\246");
+    }
+}
@@ -0,0 +1,51 @@
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+use crate::ledger::{self, ZondaxRepr};
+
+/// Writes test vectors as CSV, with one row per displayed element:
+/// `id,name,mode,element,label,value,pages`.
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, data: &[ZondaxRepr]) -> io::Result<()> {
+    writeln!(writer, "id,name,mode,element,label,value,pages")?;
+    for repr in data {
+        for (mode, pages) in [("regular", repr.output()), ("expert", repr.output_expert())] {
+            for (idx, element) in ledger::displayed_elements(pages).iter().enumerate() {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{}",
+                    field(repr.id()),
+                    field(repr.name()),
+                    mode,
+                    idx,
+                    field(&element.label),
+                    field(&element.value),
+                    element.page_count
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Quotes the field if it contains characters with a special meaning in CSV.
+fn field(value: &str) -> Cow<str> {
+    if value.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::field;
+
+    #[test]
+    fn quotes_special_characters() {
+        assert_eq!(field("Transfer"), "Transfer");
+        assert_eq!(field("1,000"), "\"1,000\"");
+        assert_eq!(field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// How to treat the existing content of the output directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DirMode {
+    /// Wipe the directory and write the whole vector set anew.
+    Clean,
+    /// Overwrite only the regenerated files that changed, keeping all the others.
+    Merge,
+}
+
+/// Output directory whose files are first written to a staging directory
+/// and moved into place only once the whole vector set has been generated,
+/// so that a failed run never leaves a partially written vector set behind.
+pub(crate) struct StagedDir {
+    target: PathBuf,
+    staging: PathBuf,
+    mode: Option<DirMode>,
+    committed: bool,
+}
+
+impl StagedDir {
+    /// Creates the staging directory next to the `target`.
+    ///
+    /// Without a `mode`, the target directory must be empty or missing.
+    pub(crate) fn create(target: &Path, mode: Option<DirMode>) -> io::Result<Self> {
+        if mode.is_none() && is_non_empty_dir(target)? {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} is not empty, use --clean or --merge", target.display()),
+            ));
+        }
+        let staging = sibling(target, "tmp");
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        fs::create_dir_all(&staging)?;
+        Ok(StagedDir {
+            target: target.to_path_buf(),
+            staging,
+            mode,
+            committed: false,
+        })
+    }
+
+    /// Directory to write the files to.
+    pub(crate) fn path(&self) -> &Path {
+        &self.staging
+    }
+
+    /// Moves the written files into the target directory.
+    pub(crate) fn commit(mut self) -> io::Result<()> {
+        match self.mode {
+            Some(DirMode::Merge) => {
+                fs::create_dir_all(&self.target)?;
+                move_files(&self.staging, &self.target)?;
+                fs::remove_dir_all(&self.staging)?;
+            }
+            Some(DirMode::Clean) | None => {
+                // Renames are atomic, so the target holds either the old or the new vector set.
+                let backup = sibling(&self.target, "old");
+                if self.target.exists() {
+                    fs::rename(&self.target, &backup)?;
+                }
+                fs::rename(&self.staging, &self.target)?;
+                if backup.exists() {
+                    fs::remove_dir_all(&backup)?;
+                }
+            }
+        }
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for StagedDir {
+    fn drop(&mut self) {
+        if !self.committed {
+            // Best effort – leftovers are removed by the next run anyway.
+            let _ = fs::remove_dir_all(&self.staging);
+        }
+    }
+}
+
+fn is_non_empty_dir(path: &Path) -> io::Result<bool> {
+    if !path.exists() {
+        return Ok(false);
+    }
+    Ok(fs::read_dir(path)?.next().is_some())
+}
+
+// Returns a hidden path next to the `path`, on the same file system – so that it can be renamed.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.{}", name, suffix))
+}
+
+// Moves every file from `from` into `to`, overwriting existing ones.
+// Files with unchanged content are left untouched, keeping their modification times.
+fn move_files(from: &Path, to: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let destination = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            fs::create_dir_all(&destination)?;
+            move_files(&entry.path(), &destination)?;
+        } else if !is_same_content(&entry.path(), &destination)? {
+            fs::rename(entry.path(), destination)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_same_content(path: &Path, other: &Path) -> io::Result<bool> {
+    if !other.exists() || fs::metadata(path)?.len() != fs::metadata(other)?.len() {
+        return Ok(false);
+    }
+    Ok(fs::read(path)? == fs::read(other)?)
+}
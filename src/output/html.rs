@@ -0,0 +1,82 @@
+use std::io::{self, Write};
+
+use crate::ledger::{is_warning_label, ZondaxRepr, LEDGER_VIEW_TOP_ROW_CHAR_COUNT};
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+.screens { display: flex; flex-wrap: wrap; gap: 8px; }
+.screen { background: #111; color: #eee; font-family: monospace; width: 19ch; padding: 8px;
+  border-radius: 6px; white-space: pre; }
+.label { text-align: center; font-weight: bold; margin-bottom: 4px; }
+.warning { background: #7a1f00; }";
+
+/// Writes a static HTML page rendering the Ledger screens of every sample,
+/// so that they can be reviewed without a device.
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, data: &[ZondaxRepr]) -> io::Result<()> {
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html>")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(writer, "<title>Test vectors</title>")?;
+    writeln!(writer, "<style>\n{}\n</style>", STYLE)?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+    for repr in data {
+        writeln!(
+            writer,
+            "<h2 id=\"vector-{}\">{}. {}</h2>",
+            repr.index(),
+            repr.index(),
+            escape(repr.name())
+        )?;
+        writeln!(writer, "<h3>Regular</h3>")?;
+        write_screens(writer, repr.output())?;
+        writeln!(writer, "<h3>Expert</h3>")?;
+        write_screens(writer, repr.output_expert())?;
+    }
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")
+}
+
+fn write_screens<W: Write + ?Sized>(writer: &mut W, pages: &[String]) -> io::Result<()> {
+    writeln!(writer, "<div class=\"screens\">")?;
+    for page in pages {
+        // Pages are formatted as `<idx> | <label> : <value>`,
+        // where value is the concatenation of top and bottom rows of the screen.
+        let page = page
+            .split_once(" | ")
+            .map_or(page.as_str(), |(_, page)| page);
+        let (label, value) = page.split_once(" : ").unwrap_or((page, ""));
+        let top: String = value.chars().take(LEDGER_VIEW_TOP_ROW_CHAR_COUNT).collect();
+        let bottom: String = value.chars().skip(LEDGER_VIEW_TOP_ROW_CHAR_COUNT).collect();
+        // Warnings are displayed on a screen of their own.
+        let class = if is_warning_label(label) {
+            "screen warning"
+        } else {
+            "screen"
+        };
+        writeln!(
+            writer,
+            "<div class=\"{}\"><div class=\"label\">{}</div>{}\n{}</div>",
+            class,
+            escape(label),
+            escape(&top),
+            escape(&bottom)
+        )?;
+    }
+    writeln!(writer, "</div>")
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,11 @@
+use std::io::{self, Write};
+
+use crate::ledger::ZondaxRepr;
+
+/// Writes test vectors as a JSON array, in the structure expected by the Zondax test pipelines:
+/// per-sample `index`, `name`, `blob` (hex of the serialized sample),
+/// `output` (regular mode pages) and `output_expert` (expert mode pages).
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, data: &[ZondaxRepr]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *writer, data)?;
+    writeln!(writer)
+}
@@ -0,0 +1,97 @@
+use std::io::{self, Write};
+
+use crate::ledger::{is_warning_label, SectionSpan, ZondaxRepr};
+
+/// Writes test vectors as a single Markdown document meant for human review:
+/// one section per sample, with tables of pages displayed in regular and expert modes –
+/// a table per section of the deploy, if the sections were recorded.
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, data: &[ZondaxRepr]) -> io::Result<()> {
+    writeln!(writer, "# Test vectors")?;
+    for repr in data {
+        writeln!(writer)?;
+        writeln!(writer, "## {}. {}", repr.index(), escape(repr.name()))?;
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "* Valid: {} (regular), {} (expert)",
+            yes_no(repr.valid_regular()),
+            yes_no(repr.valid_expert())
+        )?;
+        writeln!(writer, "* Blob: {} bytes", repr.blob().len() / 2)?;
+        writeln!(writer)?;
+        writeln!(writer, "### Regular")?;
+        writeln!(writer)?;
+        write_sections(writer, repr.output(), repr.output_sections())?;
+        writeln!(writer)?;
+        writeln!(writer, "### Expert")?;
+        writeln!(writer)?;
+        write_sections(writer, repr.output_expert(), repr.output_expert_sections())?;
+    }
+    Ok(())
+}
+
+fn write_sections<W: Write + ?Sized>(
+    writer: &mut W,
+    pages: &[String],
+    sections: &[SectionSpan],
+) -> io::Result<()> {
+    if sections.is_empty() {
+        return write_pages(writer, pages);
+    }
+    let mut first = 0;
+    for (idx, span) in sections.iter().enumerate() {
+        if idx > 0 {
+            writeln!(writer)?;
+        }
+        writeln!(writer, "#### {:?}", span.section)?;
+        writeln!(writer)?;
+        let last = (first + span.pages).min(pages.len());
+        write_pages(writer, &pages[first..last])?;
+        first = last;
+    }
+    Ok(())
+}
+
+fn write_pages<W: Write + ?Sized>(writer: &mut W, pages: &[String]) -> io::Result<()> {
+    writeln!(writer, "| # | Label | Value |")?;
+    writeln!(writer, "|---|-------|-------|")?;
+    for page in pages {
+        // Pages are formatted as `<idx> | <label> : <value>`.
+        let (idx, page) = page.split_once(" | ").unwrap_or(("", page));
+        let (label, value) = page.split_once(" : ").unwrap_or((page, ""));
+        // Warnings are displayed on a screen of their own, so they stand out in review too.
+        let emphasis = if is_warning_label(label) { "**" } else { "" };
+        writeln!(
+            writer,
+            "| {} | {}{}{} | {}{}{} |",
+            idx,
+            emphasis,
+            escape(label),
+            emphasis,
+            emphasis,
+            escape(value),
+            emphasis
+        )?;
+    }
+    Ok(())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+// Escapes characters that would break the table or the formatting.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '|' | '_' | '*' | '`' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,9 @@
+use std::io::{self, Write};
+
+use crate::ledger::ZondaxRepr;
+
+/// Writes a single test vector as one line of JSON.
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, repr: &ZondaxRepr) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, repr)?;
+    writeln!(writer)
+}
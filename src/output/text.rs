@@ -0,0 +1,19 @@
+use std::io::{self, Write};
+
+use crate::ledger::ZondaxRepr;
+
+/// Writes the test vector as plain text: its identifier followed by the pages
+/// displayed in regular and expert modes. Modes without pages are skipped.
+pub(crate) fn write<W: Write + ?Sized>(writer: &mut W, repr: &ZondaxRepr) -> io::Result<()> {
+    writeln!(writer, "# {}", repr.id())?;
+    for (mode, pages) in [("regular", repr.output()), ("expert", repr.output_expert())] {
+        if pages.is_empty() {
+            continue;
+        }
+        writeln!(writer, "[{}]", mode)?;
+        for page in pages {
+            writeln!(writer, "{}", page)?;
+        }
+    }
+    Ok(())
+}
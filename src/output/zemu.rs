@@ -0,0 +1,40 @@
+use std::{fs, io, path::Path};
+
+use crate::ledger::ZondaxRepr;
+
+/// Devices exercised by the Ledger app's Zemu tests.
+/// All of them display the elements with the same pagination.
+const DEVICES: [&str; 3] = ["nanos", "nanosp", "nanox"];
+
+/// Writes expected screens of the sample as text snapshots, in the following layout:
+/// `<dir>/<device>/<id>.txt` – pages displayed in regular mode,
+/// `<dir>/<device>/<id>_expert.txt` – pages displayed in expert mode.
+///
+/// Files are named after the stable identifier of the sample,
+/// so that adding a sample doesn't rename the snapshots of the others.
+/// Modes without pages (filtered out on the command line) have no snapshot.
+pub(crate) fn write_snapshot(dir: &Path, repr: &ZondaxRepr) -> io::Result<()> {
+    let name = repr.id();
+    for device in DEVICES {
+        let device_dir = dir.join(device);
+        fs::create_dir_all(&device_dir)?;
+        if !repr.output().is_empty() {
+            fs::write(
+                device_dir.join(format!("{}.txt", name)),
+                snapshot(repr.output()),
+            )?;
+        }
+        if !repr.output_expert().is_empty() {
+            fs::write(
+                device_dir.join(format!("{}_expert.txt", name)),
+                snapshot(repr.output_expert()),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// One page per line.
+fn snapshot(pages: &[String]) -> String {
+    pages.iter().map(|page| format!("{}\n", page)).collect()
+}
@@ -1,46 +1,333 @@
+mod account_mgmt;
 mod auction;
-mod deploy;
+mod cep18;
+mod cep78;
+pub(crate) mod deploy;
+mod faucet;
+mod heuristics;
+mod module;
 mod runtime_args;
+mod system;
 mod utils;
 
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_node::types::Deploy;
+use casper_types::{bytesrepr::ToBytes, system::mint::ARG_AMOUNT, U512};
+use thousands::Separable;
+
+use std::vec;
 
 use crate::{
-    checksummed_hex,
-    ledger::{Element, TxnPhase},
+    currency::Currency,
+    error::ParseError,
+    ledger::{self, Element, Tier, TxnPhase},
     message::CasperMessage,
-    parser::deploy::{parse_approvals, parse_deploy_header, parse_phase},
+    parser::deploy::{
+        parse_approvals, parse_deploy_header, parse_hashes, parse_phase, stored_contract_call,
+    },
+    Options,
 };
 
 pub(crate) fn parse_message(m: CasperMessage) -> Vec<Element> {
     vec![Element::regular("Msg hash", hex::encode(m.hashed()))]
 }
 
-pub(crate) fn parse_deploy(d: Deploy) -> Vec<Element> {
-    let mut elements = vec![];
-    elements.push(Element::regular(
-        "Txn hash",
-        checksummed_hex::encode(d.hash().inner()).to_string(),
-    ));
-    elements.push(deploy_type(&d));
-    elements.extend(parse_deploy_header(d.header()));
-    elements.extend(parse_phase(d.payment(), TxnPhase::Payment));
-    elements.extend(parse_phase(d.session(), TxnPhase::Session));
-    elements.extend(parse_approvals(&d));
+pub(crate) fn parse_deploy(d: &Deploy) -> Result<Vec<Element>, ParseError> {
+    ElementStream::new(d, &Options::default().expert(true)).collect()
+}
+
+/// Sections of the deploy, in the order of display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Hash,
+    Type,
+    Header,
+    Payment,
+    Session,
+    Approvals,
+    Done,
+}
+
+/// Iterator over the elements the Ledger displays for a deploy, in the order of display.
+///
+/// The deploy is parsed lazily, one section at a time – so that the first elements
+/// of a deploy with huge arguments can be emitted before its last ones are parsed.
+/// Nothing is yielded after the first error.
+pub struct ElementStream<'a> {
+    deploy: &'a Deploy,
+    options: Options,
+    next_section: Section,
+    parsed: vec::IntoIter<Element>,
+}
+
+impl<'a> ElementStream<'a> {
+    pub(crate) fn new(deploy: &'a Deploy, options: &Options) -> Self {
+        ElementStream {
+            deploy,
+            options: options.clone(),
+            next_section: Section::Hash,
+            parsed: vec![].into_iter(),
+        }
+    }
+
+    fn parse_section(&self, section: Section) -> Result<Vec<Element>, ParseError> {
+        let d = self.deploy;
+        match section {
+            Section::Hash => parse_hashes(d),
+            Section::Type => Ok(vec![deploy_type(d)]),
+            Section::Header => parse_deploy_header(d.header(), self.options.timezone_offset),
+            Section::Payment => self.parse_item(d.payment(), TxnPhase::Payment),
+            Section::Session => self.parse_item(d.session(), TxnPhase::Session),
+            Section::Approvals => parse_approvals(d, self.options.verify_signatures),
+            Section::Done => Ok(vec![]),
+        }
+    }
+
+    // Elements of the payment or session item (as decoded by the first recognizer
+    // of it, if any), followed by the ones of the known contract called.
+    fn parse_item(
+        &self,
+        item: &ExecutableDeployItem,
+        phase: TxnPhase,
+    ) -> Result<Vec<Element>, ParseError> {
+        let recognized = self
+            .options
+            .recognizers
+            .iter()
+            .find_map(|recognizer| recognizer.recognize(item));
+        let mut elements = match recognized {
+            Some(elements) => elements,
+            None => {
+                let default_currency = Currency::default();
+                let currency = self
+                    .options
+                    .currencies
+                    .as_ref()
+                    .and_then(|currencies| currencies.of(self.deploy.header().chain_name()))
+                    .unwrap_or(&default_currency);
+                let mut elements =
+                    parse_phase(item, phase, self.options.policy.as_deref(), currency)?;
+                if DeployKind::of(item) == DeployKind::ContractExecution {
+                    if self.options.guess_token_transfers {
+                        elements.extend(heuristics::token_transfer(item)?);
+                    }
+                    // Without a registry, no entry point is known to be unknown.
+                    let known = self
+                        .options
+                        .registry
+                        .as_ref()
+                        .map_or(true, |registry| registry.knows_entry_point(item));
+                    if stored_contract_call(item).is_some() && !known {
+                        elements.push(Element::warning("unknown entry point".to_string()));
+                    }
+                }
+                elements
+            }
+        };
+        if let Some(registry) = &self.options.registry {
+            elements.extend(registry.elements(item)?);
+        }
+        if system::is_named_as_system_contract(item) {
+            elements.push(Element::warning("unverified system contract".to_string()));
+        }
+        elements.push(Element::debug(
+            "Args size",
+            format!("{} bytes", item.args().serialized_length()),
+        ));
+        if let Some(threshold) = self.options.amount_warning_threshold {
+            let amount = item
+                .args()
+                .get(ARG_AMOUNT)
+                .and_then(|cl_value| cl_value.clone().into_t::<U512>().ok());
+            if let Some(amount) = amount.filter(|amount| *amount > threshold) {
+                elements.push(Element::warning(format!(
+                    "amount above {} motes",
+                    threshold.separate_with_spaces()
+                )));
+            }
+        }
+        Ok(elements)
+    }
+
+    // Tags the elements of the section with the section of the display they belong to,
+    // if requested.
+    fn grouped(&self, section: Section, elements: Vec<Element>) -> Vec<Element> {
+        if !self.options.sections {
+            return elements;
+        }
+        let d = self.deploy;
+        let (item, phase) = match section {
+            Section::Hash | Section::Type | Section::Header => {
+                return in_section(elements, ledger::Section::Header)
+            }
+            Section::Approvals | Section::Done => {
+                return in_section(elements, ledger::Section::Approvals)
+            }
+            Section::Payment => (d.payment(), TxnPhase::Payment),
+            Section::Session => (d.session(), TxnPhase::Session),
+        };
+        // Items are described first – by their kind, address and entry point –
+        // whatever follows is decoded from their arguments.
+        let mut described = deploy::deploy_type(phase, item).len();
+        if elements
+            .get(described)
+            .map_or(false, |element| element.label() == "Entry-point")
+        {
+            described += 1;
+        }
+        elements
+            .into_iter()
+            .enumerate()
+            .map(|(idx, element)| {
+                if idx < described {
+                    element.in_section(ledger::Section::Execution)
+                } else {
+                    element.in_section(ledger::Section::Arguments)
+                }
+            })
+            .collect()
+    }
+}
+
+fn in_section(elements: Vec<Element>, section: ledger::Section) -> Vec<Element> {
     elements
+        .into_iter()
+        .map(|element| element.in_section(section))
+        .collect()
+}
+
+impl Iterator for ElementStream<'_> {
+    type Item = Result<Element, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(element) = self.parsed.next() {
+                let element = match &self.options.label_aliases {
+                    Some(aliases) => aliases.apply(element),
+                    None => element,
+                };
+                if self.options.strict {
+                    // Expert-only elements are checked too, the user may switch to expert mode.
+                    if let Err(err) = element.check_displayable() {
+                        self.next_section = Section::Done;
+                        self.parsed = vec![].into_iter();
+                        return Some(Err(err));
+                    }
+                }
+                let included = match element.tier() {
+                    Tier::Regular => true,
+                    // The debug tier is below the expert mode, so it includes the expert elements.
+                    Tier::Expert => self.options.expert || self.options.debug,
+                    Tier::Debug => self.options.debug,
+                };
+                if included {
+                    return Some(Ok(element));
+                }
+            }
+            let section = self.next_section;
+            self.next_section = match section {
+                Section::Hash => Section::Type,
+                Section::Type => Section::Header,
+                Section::Header => Section::Payment,
+                Section::Payment => Section::Session,
+                Section::Session => Section::Approvals,
+                Section::Approvals | Section::Done => Section::Done,
+            };
+            if section == Section::Done {
+                return None;
+            }
+            match self.parse_section(section) {
+                Ok(elements) => self.parsed = self.grouped(section, elements).into_iter(),
+                Err(err) => {
+                    self.next_section = Section::Done;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// High-level kind of the deploy, as recognized from its session item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeployKind {
+    Delegate,
+    Undelegate,
+    Redelegate,
+    AccountMgmt,
+    Faucet,
+    Cep18,
+    Cep78,
+    Transfer,
+    ContractExecution,
+}
+
+impl DeployKind {
+    pub(crate) fn of(session: &ExecutableDeployItem) -> Self {
+        if auction::is_delegate(session) {
+            DeployKind::Delegate
+        } else if auction::is_undelegate(session) {
+            DeployKind::Undelegate
+        } else if auction::is_redelegate(session) {
+            DeployKind::Redelegate
+        } else if account_mgmt::is_account_mgmt(session) {
+            DeployKind::AccountMgmt
+        } else if faucet::is_faucet(session) {
+            DeployKind::Faucet
+        } else if cep18::is_cep18(session) {
+            DeployKind::Cep18
+        } else if cep78::is_cep78(session) {
+            DeployKind::Cep78
+        } else if session.is_transfer() {
+            DeployKind::Transfer
+        } else {
+            DeployKind::ContractExecution
+        }
+    }
+
+    /// Returns `true` for the recognized auction calls.
+    pub(crate) fn is_auction(&self) -> bool {
+        matches!(
+            self,
+            DeployKind::Delegate | DeployKind::Undelegate | DeployKind::Redelegate
+        )
+    }
+
+    /// Returns `true` for the recognized calls of token contracts.
+    pub(crate) fn is_token(&self) -> bool {
+        matches!(self, DeployKind::Cep18 | DeployKind::Cep78)
+    }
+
+    /// Machine-friendly name of the kind.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            DeployKind::Delegate => "delegate",
+            DeployKind::Undelegate => "undelegate",
+            DeployKind::Redelegate => "redelegate",
+            DeployKind::AccountMgmt => "account_mgmt",
+            DeployKind::Faucet => "faucet",
+            DeployKind::Cep18 => "cep18",
+            DeployKind::Cep78 => "cep78",
+            DeployKind::Transfer => "transfer",
+            DeployKind::ContractExecution => "contract_execution",
+        }
+    }
+
+    /// Label displayed in the Ledger.
+    fn label(&self) -> &'static str {
+        match self {
+            DeployKind::Delegate => "Delegate",
+            DeployKind::Undelegate => "Undelegate",
+            DeployKind::Redelegate => "Redelegate",
+            DeployKind::AccountMgmt => "Account mgmt",
+            DeployKind::Faucet => "Faucet",
+            DeployKind::Cep18 => "CEP-18 token",
+            DeployKind::Cep78 => "CEP-78 NFT",
+            DeployKind::Transfer => "Token transfer",
+            DeployKind::ContractExecution => "Contract execution",
+        }
+    }
 }
 
 fn deploy_type(d: &Deploy) -> Element {
-    let dtype = if auction::is_delegate(d.session()) {
-        "Delegate"
-    } else if auction::is_undelegate(d.session()) {
-        "Undelegate"
-    } else if auction::is_redelegate(d.session()) {
-        "Redelegate"
-    } else if d.session().is_transfer() {
-        "Token transfer"
-    } else {
-        "Contract execution"
-    };
-    Element::regular("Type", dtype.to_string())
+    Element::regular("Type", DeployKind::of(d.session()).label().to_string())
 }
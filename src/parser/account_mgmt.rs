@@ -0,0 +1,143 @@
+//! Management of the associated keys and action thresholds of an account.
+//!
+//! Such deploys decide who controls the account, so their arguments are displayed
+//! instead of the digest of them – whether they're sent as session code or as a call
+//! of a stored contract.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::ACCOUNT_HASH_LENGTH, CLType, RuntimeArgs};
+
+use crate::{
+    error::ParseError,
+    ledger::{Element, TxnPhase},
+    parser::deploy::{deploy_type, stored_contract_call},
+};
+
+use super::runtime_args::parse_optional_arg;
+
+const ACCOUNT_MGMT_ENTRYPOINTS: [&str; 4] = [
+    "add_associated_key",
+    "update_associated_key",
+    "remove_associated_key",
+    "set_action_thresholds",
+];
+const ACCOUNT_ARG_KEY: &str = "account";
+const WEIGHT_ARG_KEY: &str = "weight";
+const DEPLOYMENT_THRESHOLD_ARG_KEY: &str = "deployment_threshold";
+const KEY_MANAGEMENT_THRESHOLD_ARG_KEY: &str = "key_management_threshold";
+
+/// Returns `true` when the item has only the arguments of key management,
+/// and calls one of its entry points if it's a stored contract.
+pub(crate) fn is_account_mgmt(item: &ExecutableDeployItem) -> bool {
+    let args = match item {
+        ExecutableDeployItem::ModuleBytes { module_bytes, args }
+            if !module_bytes.inner_bytes().is_empty() =>
+        {
+            args
+        }
+        ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+            return false
+        }
+        ExecutableDeployItem::StoredContractByHash { .. }
+        | ExecutableDeployItem::StoredContractByName { .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { .. } => {
+            match stored_contract_call(item) {
+                Some((entry_point, args)) if ACCOUNT_MGMT_ENTRYPOINTS.contains(&entry_point) => {
+                    args
+                }
+                _ => return false,
+            }
+        }
+    };
+    has_account_mgmt_args(args)
+}
+
+pub(crate) fn parse_account_mgmt(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let args = item.args();
+    let mut elements = deploy_type(phase, item);
+    if let Some((entry_point, _)) = stored_contract_call(item) {
+        elements.push(Element::regular("Entry-point", entry_point.to_string()));
+    }
+    elements.extend(parse_optional_arg(
+        args,
+        ACCOUNT_ARG_KEY,
+        "Account",
+        false,
+        Ok,
+    )?);
+    elements.extend(parse_optional_arg(
+        args,
+        WEIGHT_ARG_KEY,
+        "Weight",
+        false,
+        Ok,
+    )?);
+    elements.extend(parse_optional_arg(
+        args,
+        DEPLOYMENT_THRESHOLD_ARG_KEY,
+        "Deploy thr",
+        false,
+        Ok,
+    )?);
+    elements.extend(parse_optional_arg(
+        args,
+        KEY_MANAGEMENT_THRESHOLD_ARG_KEY,
+        "Mgmt thr",
+        false,
+        Ok,
+    )?);
+    Ok(elements)
+}
+
+// At least one of the arguments, all of them of the expected types –
+// with any other argument, the deploy may do more than managing the keys.
+fn has_account_mgmt_args(args: &RuntimeArgs) -> bool {
+    let account_types = [
+        CLType::ByteArray(ACCOUNT_HASH_LENGTH as u32),
+        CLType::PublicKey,
+    ];
+    !args.is_empty()
+        && args.named_args().all(|arg| match arg.name() {
+            ACCOUNT_ARG_KEY => account_types.contains(arg.cl_value().cl_type()),
+            WEIGHT_ARG_KEY | DEPLOYMENT_THRESHOLD_ARG_KEY | KEY_MANAGEMENT_THRESHOLD_ARG_KEY => {
+                arg.cl_value().cl_type() == &CLType::U8
+            }
+            _ => false,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{account::AccountHash, bytesrepr::Bytes, runtime_args, RuntimeArgs, U512};
+
+    use super::is_account_mgmt;
+
+    fn session(args: RuntimeArgs) -> ExecutableDeployItem {
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::from(vec![0u8; 8]),
+            args,
+        }
+    }
+
+    #[test]
+    fn sessions_with_other_arguments_are_not_recognized() {
+        let account = AccountHash::new([2u8; 32]);
+        assert!(is_account_mgmt(&session(runtime_args! {
+            "account" => account,
+            "weight" => 1u8,
+        })));
+        assert!(!is_account_mgmt(&session(runtime_args! {
+            "account" => account,
+            "weight" => 1u8,
+            "amount" => U512::one(),
+        })));
+        assert!(!is_account_mgmt(&session(runtime_args! {
+            "weight" => 1u64,
+        })));
+    }
+}
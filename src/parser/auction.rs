@@ -2,87 +2,105 @@ use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_types::{system::mint, RuntimeArgs};
 
 use crate::{
+    currency::Currency,
+    error::ParseError,
     ledger::{Element, TxnPhase},
-    parser::deploy::{deploy_type, parse_amount},
+    parser::deploy::{deploy_type, parse_amount, parse_generic_item},
 };
 
-use super::{deploy::identity, runtime_args::parse_optional_arg};
+use super::runtime_args::parse_optional_arg;
 
 fn parse_auction_item<'a, F>(
     method: &str,
     item: &'a ExecutableDeployItem,
+    currency: &Currency,
     args_parser: F,
-) -> Vec<Element>
+) -> Result<Vec<Element>, ParseError>
 where
-    F: Fn(&'a RuntimeArgs) -> Vec<Element>,
+    F: Fn(&'a RuntimeArgs) -> Result<Vec<Element>, ParseError>,
 {
-    let mut elements = vec![];
-    elements.extend(
-        deploy_type(TxnPhase::Session, item)
-            .into_iter()
-            .map(|mut e| {
-                // For now, we choose to not display deploy's details for delegation.
-                e.as_expert();
-                e
-            }),
-    );
     match item {
         ExecutableDeployItem::Transfer { .. } => {
-            panic!("unexpected type for {}", method)
+            // Native transfers can't call the auction, whatever their arguments suggest.
+            let mut elements = vec![Element::warning(format!(
+                "{} call in a native transfer",
+                method
+            ))];
+            elements.extend(parse_generic_item(item, TxnPhase::Session, &[], currency)?);
+            Ok(elements)
         }
         ExecutableDeployItem::StoredContractByHash { args, .. }
         | ExecutableDeployItem::StoredContractByName { args, .. }
         | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
         | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
         | ExecutableDeployItem::ModuleBytes { args, .. } => {
-            elements.extend(args_parser(args));
+            let mut elements = vec![];
+            elements.extend(
+                deploy_type(TxnPhase::Session, item)
+                    .into_iter()
+                    .map(|mut e| {
+                        // For now, we choose to not display deploy's details for delegation.
+                        e.as_expert();
+                        e
+                    }),
+            );
+            elements.extend(args_parser(args)?);
+            Ok(elements)
         }
-    };
-    elements
+    }
 }
 
-pub(crate) fn parse_delegation(item: &ExecutableDeployItem) -> Vec<Element> {
-    let arg_parser = |args| {
+pub(crate) fn parse_delegation(
+    item: &ExecutableDeployItem,
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args| -> Result<Vec<Element>, ParseError> {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args));
+        elements.extend(parse_delegator(args)?);
         // Public key of the validator we're delegating to.
-        elements.extend(parse_validator(args));
+        elements.extend(parse_validator(args)?);
         // Amount we're delegating.
-        elements.extend(parse_amount(args));
-        elements
+        elements.extend(parse_amount(args, currency));
+        Ok(elements)
     };
-    parse_auction_item("delegate", item, arg_parser)
+    parse_auction_item("delegate", item, currency, arg_parser)
 }
 
-pub(crate) fn parse_undelegation(item: &ExecutableDeployItem) -> Vec<Element> {
-    let arg_parser = |args| {
+pub(crate) fn parse_undelegation(
+    item: &ExecutableDeployItem,
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args| -> Result<Vec<Element>, ParseError> {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args));
+        elements.extend(parse_delegator(args)?);
         // Public key of the validator we're delegating to.
-        elements.extend(parse_validator(args));
+        elements.extend(parse_validator(args)?);
         // Amount we're delegating.
-        elements.extend(parse_amount(args));
-        elements
+        elements.extend(parse_amount(args, currency));
+        Ok(elements)
     };
-    parse_auction_item("undelegate", item, arg_parser)
+    parse_auction_item("undelegate", item, currency, arg_parser)
 }
 
-pub(crate) fn parse_redelegation(item: &ExecutableDeployItem) -> Vec<Element> {
-    let arg_parser = |args| {
+pub(crate) fn parse_redelegation(
+    item: &ExecutableDeployItem,
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let arg_parser = |args| -> Result<Vec<Element>, ParseError> {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args));
+        elements.extend(parse_delegator(args)?);
         // Public key of the current validator we have been redelagating to so far.
-        elements.extend(parse_old_validator(args));
+        elements.extend(parse_old_validator(args)?);
         // New validator we're redelegating to.
-        elements.extend(parse_new_validator(args));
+        elements.extend(parse_new_validator(args)?);
         // Amount we're delegating.
-        elements.extend(parse_amount(args));
-        elements
+        elements.extend(parse_amount(args, currency));
+        Ok(elements)
     };
-    parse_auction_item("redelegate", item, arg_parser)
+    parse_auction_item("redelegate", item, currency, arg_parser)
 }
 
 /// Returns `true` when the deploy's entry point is *literally* _delegate_
@@ -107,13 +125,15 @@ fn get_auction_arg(item: &ExecutableDeployItem) -> Option<String> {
     match item {
         // ModuleBytes variant does not have an entry point, it defaults to `call()`,
         // so we expect a special named argument called `auction` when detecting auction contract calls.
-        ExecutableDeployItem::ModuleBytes { args, .. } => args.get("auction").map(|cl_value| {
-            cl_value
-                .clone()
-                .into_t::<String>()
-                .expect("argument should be string")
-        }),
-        _ => None,
+        // An `auction` argument of any other type doesn't name an auction call.
+        ExecutableDeployItem::ModuleBytes { args, .. } => args
+            .get("auction")
+            .and_then(|cl_value| cl_value.clone().into_t::<String>().ok()),
+        ExecutableDeployItem::StoredContractByHash { .. }
+        | ExecutableDeployItem::StoredContractByName { .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { .. }
+        | ExecutableDeployItem::Transfer { .. } => None,
     }
 }
 
@@ -161,20 +181,20 @@ fn has_redelegate_arg(item: &ExecutableDeployItem) -> bool {
         && item.args().get(mint::ARG_AMOUNT).is_some()
 }
 
-fn parse_delegator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, DELEGATOR_ARG_KEY, "delegator", false, identity)
+fn parse_delegator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, DELEGATOR_ARG_KEY, "Delegator", false, Ok)
 }
 
-fn parse_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, VALIDATOR_ARG_KEY, "validator", false, identity)
+fn parse_validator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, VALIDATOR_ARG_KEY, "Validator", false, Ok)
 }
 
-fn parse_old_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, VALIDATOR_ARG_KEY, "old", false, identity)
+fn parse_old_validator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, VALIDATOR_ARG_KEY, "Old", false, Ok)
 }
 
-fn parse_new_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, NEW_VALIDATOR_ARG_KEY, "new", false, identity)
+fn parse_new_validator(args: &RuntimeArgs) -> Result<Option<Element>, ParseError> {
+    parse_optional_arg(args, NEW_VALIDATOR_ARG_KEY, "New", false, Ok)
 }
 
 fn is_entrypoint(item: &ExecutableDeployItem, expected: &str) -> bool {
@@ -188,3 +208,28 @@ fn is_entrypoint(item: &ExecutableDeployItem, expected: &str) -> bool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{runtime_args, RuntimeArgs, U512};
+
+    use super::parse_delegation;
+    use crate::currency::Currency;
+
+    #[test]
+    fn delegation_in_native_transfer_is_rendered_as_transfer() {
+        let item = ExecutableDeployItem::Transfer {
+            args: runtime_args! {
+                "amount" => U512::from(10u8),
+                "delegator" => "delegator".to_string(),
+                "validator" => "validator".to_string(),
+            },
+        };
+        let elements = parse_delegation(&item, &Currency::default()).unwrap();
+        assert_eq!(elements[0].label(), "Warning");
+        assert!(elements
+            .iter()
+            .any(|element| element.label() == "Amount" && element.value() == "10 motes"));
+    }
+}
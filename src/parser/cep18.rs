@@ -0,0 +1,157 @@
+//! Calls of the CEP-18 fungible token contracts, recognized by their entry point
+//! and arguments – so that the Ledger displays the token operation instead of
+//! the digest of its arguments.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{CLType, RuntimeArgs, U256};
+use thousands::Separable;
+
+use crate::{
+    error::ParseError,
+    ledger::{Element, TxnPhase},
+    parser::deploy::{deploy_type, stored_contract_call},
+};
+
+use super::runtime_args::{has_arg_of_type, parse_optional_arg};
+
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const APPROVE_ENTRYPOINT: &str = "approve";
+const TRANSFER_FROM_ENTRYPOINT: &str = "transfer_from";
+const RECIPIENT_ARG_KEY: &str = "recipient";
+const SPENDER_ARG_KEY: &str = "spender";
+const OWNER_ARG_KEY: &str = "owner";
+const AMOUNT_ARG_KEY: &str = "amount";
+
+/// Returns `true` when the item calls a CEP-18 entry point with *exactly* its arguments.
+pub(crate) fn is_cep18(item: &ExecutableDeployItem) -> bool {
+    cep18_call(item).is_some()
+}
+
+pub(crate) fn parse_cep18(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let (entry_point, key_args) = match cep18_call(item) {
+        Some(call) => call,
+        None => return Ok(vec![]),
+    };
+    let args = item.args();
+    // The token is identified by its contract only, so it's displayed in regular mode.
+    let mut elements = deploy_type(phase, item);
+    elements.push(Element::regular("Entry-point", entry_point.to_string()));
+    for (key, label) in key_args {
+        elements.extend(parse_optional_arg(args, key, label, false, Ok)?);
+    }
+    let amount = token_amount(args)?;
+    elements.push(Element::regular(
+        "Amount",
+        format!("{} tokens", amount.separate_with_spaces()),
+    ));
+    // The spender can then move all the tokens of the signer, now and in the future.
+    if entry_point == APPROVE_ENTRYPOINT && amount == U256::MAX {
+        elements.push(Element::warning("unlimited approval".to_string()));
+    }
+    Ok(elements)
+}
+
+// Returns the entry point called, along with its `Key` arguments and their labels.
+fn cep18_call(
+    item: &ExecutableDeployItem,
+) -> Option<(&str, &'static [(&'static str, &'static str)])> {
+    let (entry_point, args) = stored_contract_call(item)?;
+    let key_args: &'static [(&'static str, &'static str)] = match entry_point {
+        TRANSFER_ENTRYPOINT => &[(RECIPIENT_ARG_KEY, "Recipient")],
+        APPROVE_ENTRYPOINT => &[(SPENDER_ARG_KEY, "Spender")],
+        TRANSFER_FROM_ENTRYPOINT => &[(OWNER_ARG_KEY, "Owner"), (RECIPIENT_ARG_KEY, "Recipient")],
+        _ => return None,
+    };
+    // Calls with any other arguments may mean something else, so they're displayed as generic ones.
+    let matches = args.len() == key_args.len() + 1
+        && has_arg_of_type(args, AMOUNT_ARG_KEY, &CLType::U256)
+        && key_args
+            .iter()
+            .all(|(key, _)| has_arg_of_type(args, key, &CLType::Key));
+    matches.then_some((entry_point, key_args))
+}
+
+// The number of decimals is known to the contract only, so the amount is displayed in base units.
+fn token_amount(args: &RuntimeArgs) -> Result<U256, ParseError> {
+    args.get(AMOUNT_ARG_KEY)
+        .and_then(|cl_value| cl_value.clone().into_t::<U256>().ok())
+        .ok_or_else(|| ParseError::InvalidArgument {
+            name: AMOUNT_ARG_KEY.to_string(),
+            reason: "malformed U256".to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+    use super::{is_cep18, parse_cep18};
+    use crate::ledger::TxnPhase;
+
+    fn transfer(args: RuntimeArgs) -> ExecutableDeployItem {
+        ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([1u8; 32]),
+            entry_point: "transfer".to_string(),
+            args,
+        }
+    }
+
+    #[test]
+    fn transfer_is_rendered_as_token_transfer() {
+        let item = transfer(runtime_args! {
+            "recipient" => Key::Account(AccountHash::new([2u8; 32])),
+            "amount" => U256::from(1_000_000u64),
+        });
+        let elements = parse_cep18(&item, TxnPhase::Session).unwrap();
+        let regular: Vec<_> = elements
+            .iter()
+            .filter(|element| !element.is_expert())
+            .map(|element| element.label())
+            .collect();
+        assert_eq!(
+            regular,
+            ["Execution", "Address", "Entry-point", "Recipient", "Amount"]
+        );
+        assert_eq!(elements.last().unwrap().value(), "1 000 000 tokens");
+    }
+
+    #[test]
+    fn unlimited_approval_is_warned_about() {
+        let approve = |amount: U256| ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([1u8; 32]),
+            entry_point: "approve".to_string(),
+            args: runtime_args! {
+                "spender" => Key::Account(AccountHash::new([3u8; 32])),
+                "amount" => amount,
+            },
+        };
+        let warnings = |amount| {
+            parse_cep18(&approve(amount), TxnPhase::Session)
+                .unwrap()
+                .into_iter()
+                .filter(|element| element.is_warning())
+                .map(|element| element.value().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(warnings(U256::MAX), ["unlimited approval"]);
+        assert!(warnings(U256::one()).is_empty());
+    }
+
+    #[test]
+    fn calls_with_other_arguments_are_not_recognized() {
+        let recipient = Key::Account(AccountHash::new([2u8; 32]));
+        assert!(!is_cep18(&transfer(runtime_args! {
+            "recipient" => recipient,
+            "amount" => U256::one(),
+            "memo" => 1u64,
+        })));
+        assert!(!is_cep18(&transfer(runtime_args! {
+            "recipient" => recipient,
+            "amount" => 1u64,
+        })));
+    }
+}
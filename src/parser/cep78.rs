@@ -0,0 +1,166 @@
+//! Calls of the CEP-78 NFT contracts, recognized by their entry point and arguments –
+//! so that the Ledger displays the NFT operation instead of the digest of its arguments.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{CLType, RuntimeArgs};
+
+use crate::{
+    error::ParseError,
+    ledger::{Element, TxnPhase},
+    parser::deploy::{deploy_type, stored_contract_call},
+};
+
+use super::runtime_args::{has_arg_of_type, parse_optional_arg};
+
+const MINT_ENTRYPOINT: &str = "mint";
+const BURN_ENTRYPOINT: &str = "burn";
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const APPROVE_ENTRYPOINT: &str = "approve";
+const TOKEN_OWNER_ARG_KEY: &str = "token_owner";
+const TOKEN_META_DATA_ARG_KEY: &str = "token_meta_data";
+const TOKEN_ID_ARG_KEY: &str = "token_id";
+const TOKEN_HASH_ARG_KEY: &str = "token_hash";
+const SOURCE_KEY_ARG_KEY: &str = "source_key";
+const TARGET_KEY_ARG_KEY: &str = "target_key";
+const SPENDER_ARG_KEY: &str = "spender";
+
+/// Returns `true` when the item calls a CEP-78 entry point with *exactly* its arguments.
+pub(crate) fn is_cep78(item: &ExecutableDeployItem) -> bool {
+    cep78_call(item).is_some()
+}
+
+pub(crate) fn parse_cep78(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, ParseError> {
+    let (entry_point, key_args) = match cep78_call(item) {
+        Some(call) => call,
+        None => return Ok(vec![]),
+    };
+    let args = item.args();
+    // The collection is identified by its contract only, so it's displayed in regular mode.
+    let mut elements = deploy_type(phase, item);
+    elements.push(Element::regular("NFT", entry_point.to_string()));
+    // Tokens are identified either by their number or by their hash, depending on the collection.
+    elements.extend(parse_optional_arg(
+        args,
+        TOKEN_ID_ARG_KEY,
+        "Token ID",
+        false,
+        Ok,
+    )?);
+    elements.extend(parse_optional_arg(
+        args,
+        TOKEN_HASH_ARG_KEY,
+        "Token hash",
+        false,
+        Ok,
+    )?);
+    for (key, label) in key_args {
+        elements.extend(parse_optional_arg(args, key, label, false, Ok)?);
+    }
+    // Metadata is usually a long JSON document, spanning many pages.
+    elements.extend(parse_optional_arg(
+        args,
+        TOKEN_META_DATA_ARG_KEY,
+        "Metadata",
+        true,
+        Ok,
+    )?);
+    Ok(elements)
+}
+
+// Returns the entry point called, along with its `Key` arguments and their labels.
+fn cep78_call(
+    item: &ExecutableDeployItem,
+) -> Option<(&str, &'static [(&'static str, &'static str)])> {
+    let (entry_point, args) = stored_contract_call(item)?;
+    let key_args: &'static [(&'static str, &'static str)] = match entry_point {
+        MINT_ENTRYPOINT => &[(TOKEN_OWNER_ARG_KEY, "Owner")],
+        BURN_ENTRYPOINT => &[],
+        TRANSFER_ENTRYPOINT => &[
+            (SOURCE_KEY_ARG_KEY, "Owner"),
+            (TARGET_KEY_ARG_KEY, "Recipient"),
+        ],
+        APPROVE_ENTRYPOINT => &[(SPENDER_ARG_KEY, "Spender")],
+        _ => return None,
+    };
+    // Minted tokens are described by their metadata, the other operations name an existing token.
+    let has_token_arg = if entry_point == MINT_ENTRYPOINT {
+        has_arg_of_type(args, TOKEN_META_DATA_ARG_KEY, &CLType::String)
+    } else {
+        has_token_identifier(args)
+    };
+    // Calls with any other arguments may mean something else, so they're displayed as generic ones.
+    let matches = args.len() == key_args.len() + 1
+        && has_token_arg
+        && key_args
+            .iter()
+            .all(|(key, _)| has_arg_of_type(args, key, &CLType::Key));
+    matches.then_some((entry_point, key_args))
+}
+
+fn has_token_identifier(args: &RuntimeArgs) -> bool {
+    has_arg_of_type(args, TOKEN_ID_ARG_KEY, &CLType::U64)
+        || has_arg_of_type(args, TOKEN_HASH_ARG_KEY, &CLType::String)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
+
+    use super::{is_cep78, parse_cep78};
+    use crate::{ledger::TxnPhase, parser::cep18::is_cep18};
+
+    fn call(entry_point: &str, args: RuntimeArgs) -> ExecutableDeployItem {
+        ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([1u8; 32]),
+            entry_point: entry_point.to_string(),
+            args,
+        }
+    }
+
+    #[test]
+    fn transfer_is_told_apart_from_token_transfer() {
+        let item = call(
+            "transfer",
+            runtime_args! {
+                "token_id" => 7u64,
+                "source_key" => Key::Account(AccountHash::new([2u8; 32])),
+                "target_key" => Key::Account(AccountHash::new([3u8; 32])),
+            },
+        );
+        assert!(is_cep78(&item));
+        assert!(!is_cep18(&item));
+        let regular: Vec<_> = parse_cep78(&item, TxnPhase::Session)
+            .unwrap()
+            .into_iter()
+            .filter(|element| !element.is_expert())
+            .map(|element| (element.label().to_string(), element.value().to_string()))
+            .collect();
+        assert_eq!(regular[2], ("NFT".to_string(), "transfer".to_string()));
+        assert_eq!(regular[3], ("Token ID".to_string(), "7".to_string()));
+        assert_eq!(regular[4].0, "Owner");
+        assert_eq!(regular[5].0, "Recipient");
+    }
+
+    #[test]
+    fn burn_requires_a_single_token_identifier() {
+        assert!(is_cep78(&call(
+            "burn",
+            runtime_args! { "token_id" => 7u64 }
+        )));
+        assert!(is_cep78(&call(
+            "burn",
+            runtime_args! { "token_hash" => "abc".to_string() }
+        )));
+        assert!(!is_cep78(&call(
+            "burn",
+            runtime_args! {
+                "token_id" => 7u64,
+                "token_hash" => "abc".to_string(),
+            }
+        )));
+    }
+}
@@ -1,113 +1,217 @@
-use std::collections::BTreeMap;
-
 use crate::{
+    checksummed_hex,
+    currency::Currency,
+    error::ParseError,
     ledger::{Element, TxnPhase},
-    parser::{runtime_args::parse_optional_arg, utils::timestamp_to_seconds_res},
-    utils::parse_public_key,
+    parser::utils::{timestamp_to_seconds_res, timestamp_with_offset},
+    policy::{ArgPolicy, Policy},
+    utils::{cl_value_to_string, parse_public_key},
 };
+use std::collections::BTreeSet;
+
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_hashing::Digest;
 use casper_node::types::{Deploy, DeployHeader};
 use casper_types::{
-    bytesrepr::Bytes,
+    bytesrepr::{Bytes, ToBytes},
+    crypto,
     system::mint::{self, ARG_ID, ARG_SOURCE, ARG_TARGET, ARG_TO},
-    CLValue, RuntimeArgs, U512,
+    CLType, NamedArg, RuntimeArgs, Signature, U512,
 };
-use thousands::Separable;
 
 use super::{
+    account_mgmt::{is_account_mgmt, parse_account_mgmt},
     auction::{
         is_delegate, is_redelegate, is_undelegate, parse_delegation, parse_redelegation,
         parse_undelegation,
     },
+    cep18::{is_cep18, parse_cep18},
+    cep78::{is_cep78, parse_cep78},
+    faucet::{is_faucet, parse_faucet},
+    module::describe_module,
     runtime_args::{parse_runtime_args, parse_transfer_args},
 };
 
-pub(crate) fn parse_deploy_header(dh: &DeployHeader) -> Vec<Element> {
+/// Returns the hash of the deploy, followed by the hash of its body in expert mode –
+/// with warnings if either doesn't match the deploy's content – and the serialized lengths
+/// of the header and the body in the debug tier.
+pub(crate) fn parse_hashes(d: &Deploy) -> Result<Vec<Element>, ParseError> {
+    let header = d.header();
+    let mut elements = vec![
+        Element::regular("Txn hash", checksummed_hex::encode(d.hash().inner())),
+        Element::expert("Body hash", checksummed_hex::encode(header.body_hash())),
+    ];
+    let serialization_error =
+        |err| ParseError::InvalidValue(format!("failed to serialize the deploy: {}", err));
+    // The body hash covers the payment and session, the deploy hash covers the header.
+    let mut body = d.payment().to_bytes().map_err(serialization_error)?;
+    body.extend(d.session().to_bytes().map_err(serialization_error)?);
+    if Digest::hash(&body) != *header.body_hash() {
+        elements.push(Element::warning("body hash mismatch".to_string()));
+    }
+    let header_bytes = header.to_bytes().map_err(serialization_error)?;
+    if Digest::hash(&header_bytes) != *d.hash().inner() {
+        elements.push(Element::warning("txn hash mismatch".to_string()));
+    }
+    elements.push(Element::debug(
+        "Header size",
+        format!("{} bytes", header_bytes.len()),
+    ));
+    elements.push(Element::debug("Body size", format!("{} bytes", body.len())));
+    Ok(elements)
+}
+
+/// With the `timezone_offset` (in minutes east of UTC), the timestamp is followed
+/// by the local time in that time zone.
+pub(crate) fn parse_deploy_header(
+    dh: &DeployHeader,
+    timezone_offset: Option<i32>,
+) -> Result<Vec<Element>, ParseError> {
     let mut elements = vec![];
-    elements.push(Element::regular("chain ID", dh.chain_name().to_string()));
-    elements.push(Element::regular("account", parse_public_key(dh.account())));
+    elements.push(Element::regular("Chain ID", dh.chain_name().to_string()));
+    elements.push(parse_network(dh.chain_name()));
+    elements.push(Element::regular("Account", parse_public_key(dh.account())?));
+    // Exchanges whitelist account hashes, so the one derived from the key can be checked too.
     elements.push(Element::expert(
-        "timestamp",
-        timestamp_to_seconds_res(dh.timestamp()),
+        "Acct hash",
+        checksummed_hex::encode(dh.account().to_account_hash()),
     ));
-    elements.push(Element::expert("ttl", format!("{}", dh.ttl())));
-    elements.push(Element::expert("gas price", format!("{}", dh.gas_price())));
+    elements.push(Element::expert(
+        "Timestamp",
+        timestamp_to_seconds_res(dh.timestamp())?,
+    ));
+    if let Some(offset) = timezone_offset {
+        elements.push(Element::expert(
+            "Local time",
+            timestamp_with_offset(dh.timestamp(), offset)?,
+        ));
+    }
+    elements.push(Element::expert("Ttl", format!("{}", dh.ttl())));
+    elements.push(Element::expert("Gas price", format!("{}", dh.gas_price())));
     elements.push(Element::expert(
         "Deps #",
         format!("{:?}", dh.dependencies().len()),
     ));
-    elements
+    for (idx, dependency) in dh.dependencies().iter().enumerate() {
+        // Dep n: <hash of the n-th dependency>
+        elements.push(Element::expert(
+            format!("Dep {}", idx + 1),
+            checksummed_hex::encode(dependency.inner()),
+        ));
+    }
+    Ok(elements)
+}
+
+// Names of the public networks, so that signing for the wrong one stands out.
+const NETWORK_NAMES: [(&str, &str); 2] = [
+    ("casper", "Casper Mainnet"),
+    ("casper-test", "Casper Testnet"),
+];
+
+// The chain ID is displayed as is either way, so unknown chains are noted in expert mode only.
+fn parse_network(chain_name: &str) -> Element {
+    match NETWORK_NAMES.iter().find(|(name, _)| *name == chain_name) {
+        Some((_, network)) => Element::regular("Network", network.to_string()),
+        None => Element::expert("Network", "unknown chain".to_string()),
+    }
 }
 
-pub(crate) fn parse_phase(item: &ExecutableDeployItem, phase: TxnPhase) -> Vec<Element> {
+/// The `policy` sets the visibility of the arguments of the called entry point,
+/// and the amounts are displayed in the `currency` of the chain.
+pub(crate) fn parse_phase(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    policy: Option<&Policy>,
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let policy = policy.map_or(&[][..], |policy| policy.args(item));
     if is_delegate(item) {
-        parse_delegation(item)
+        parse_delegation(item, currency)
     } else if is_undelegate(item) {
-        parse_undelegation(item)
+        parse_undelegation(item, currency)
     } else if is_redelegate(item) {
-        parse_redelegation(item)
+        parse_redelegation(item, currency)
+    } else if is_account_mgmt(item) {
+        parse_account_mgmt(item, phase)
+    } else if is_faucet(item) {
+        parse_faucet(item, phase, currency)
+    } else if is_cep18(item) {
+        parse_cep18(item, phase)
+    } else if is_cep78(item) {
+        parse_cep78(item, phase)
     } else {
-        let mut elements: Vec<Element> = deploy_type(phase, item);
-        match item {
-            ExecutableDeployItem::ModuleBytes { module_bytes, args }
-                if is_system_payment(phase, module_bytes) =>
-            {
-                // The only required argument for the system payment is `amount`.
-                elements.extend(parse_fee(args));
-                let args_sans_amount = remove_amount_arg(args.clone());
-                if !args_sans_amount.is_empty() {
-                    // If system payment had more args than the required `amount` then they should be parsed.
-                    elements.extend(parse_runtime_args(&phase, args));
-                }
-            }
-            ExecutableDeployItem::ModuleBytes {
-                module_bytes: _,
-                args,
-            } => {
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, args));
-            }
-            ExecutableDeployItem::StoredContractByHash {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, args));
-            }
-            ExecutableDeployItem::StoredContractByName {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, args));
-            }
-            ExecutableDeployItem::StoredVersionedContractByHash {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, args));
+        parse_generic_item(item, phase, policy, currency)
+    }
+}
+
+/// Parses the item as a plain contract call or transfer, without recognizing auction calls.
+pub(crate) fn parse_generic_item(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    policy: &[ArgPolicy],
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let mut elements: Vec<Element> = deploy_type(phase, item);
+    match item {
+        ExecutableDeployItem::ModuleBytes { module_bytes, args }
+            if is_system_payment(phase, module_bytes) =>
+        {
+            // The only required argument for the system payment is `amount`.
+            elements.extend(parse_fee(args, currency));
+            // Such a deploy can't pay for its execution, so it only burns the signer's time.
+            if has_zero_amount(args) {
+                elements.push(Element::warning("zero payment".to_string()));
             }
-            ExecutableDeployItem::StoredVersionedContractByName {
-                entry_point, args, ..
-            } => {
-                elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, args));
+            if extra_args(args, &[mint::ARG_AMOUNT]).next().is_some() {
+                // If system payment had more args than the required `amount` then they should be parsed.
+                elements.extend(parse_runtime_args(&phase, args, policy)?);
             }
-            ExecutableDeployItem::Transfer { args } => {
-                elements.extend(parse_transfer_args(args));
-                let args_sans_transfer = remove_transfer_args(args.clone());
-                if !args_sans_transfer.is_empty() {
-                    println!("{:?}", args_sans_transfer);
-                    // If there are more arguments left that were not used, display digest of args.
-                    elements.extend(parse_runtime_args(&phase, args));
-                }
+        }
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: _,
+            args,
+        } => {
+            elements.extend(parse_amount(args, currency));
+            elements.extend(parse_runtime_args(&phase, args, policy)?);
+        }
+        ExecutableDeployItem::StoredContractByHash {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_amount(args, currency));
+            elements.extend(parse_runtime_args(&phase, args, policy)?);
+        }
+        ExecutableDeployItem::StoredContractByName {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_amount(args, currency));
+            elements.extend(parse_runtime_args(&phase, args, policy)?);
+        }
+        ExecutableDeployItem::StoredVersionedContractByHash {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_amount(args, currency));
+            elements.extend(parse_runtime_args(&phase, args, policy)?);
+        }
+        ExecutableDeployItem::StoredVersionedContractByName {
+            entry_point, args, ..
+        } => {
+            elements.push(entrypoint(entry_point));
+            elements.extend(parse_amount(args, currency));
+            elements.extend(parse_runtime_args(&phase, args, policy)?);
+        }
+        ExecutableDeployItem::Transfer { args } => {
+            elements.extend(parse_transfer_args(args, policy, currency)?);
+            if extra_args(args, &TRANSFER_ARGS).next().is_some() {
+                elements.extend(ambiguous_transfer_args(args));
+                // If there are more arguments left that were not used, display digest of args.
+                elements.extend(parse_runtime_args(&phase, args, policy)?);
             }
         }
-        elements
     }
+    Ok(elements)
 }
 
 /// Returns the main elements describing the deploy:
@@ -117,7 +221,7 @@ pub(crate) fn parse_phase(item: &ExecutableDeployItem, phase: TxnPhase) -> Vec<E
 /// Does NOT parse the arguments or entry points.
 pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<Element> {
     // Session|Payment :
-    let phase_label = format!("{}", phase);
+    let phase_label = phase.label();
     match item {
         ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
             if is_system_payment(phase, module_bytes) {
@@ -127,36 +231,39 @@ pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<E
                 vec![]
             } else {
                 let contract_hash = format!("{:?}", Digest::hash(module_bytes.as_slice()));
-                vec![
+                let mut elements = vec![
                     // Session|Payment: contract
-                    Element::regular(&phase_label, "contract".to_string()),
+                    Element::regular(phase_label, "contract".to_string()),
                     // Cntrct hash: <hash of contract bytes>
                     Element::regular("Cntrct hash", contract_hash),
-                ]
+                ];
+                // Module size and exports, in expert mode only.
+                elements.extend(describe_module(module_bytes.as_slice()));
+                elements
             }
         }
         ExecutableDeployItem::StoredContractByHash { hash, .. } => {
             vec![
                 // Session|Payment: by-hash
-                Element::regular(&phase_label, "by-hash".to_string()),
+                Element::regular(phase_label, "by-hash".to_string()),
                 // Address: <contract address>
-                Element::regular("address", format!("{}", hash)),
+                Element::regular("Address", format!("{}", hash)),
             ]
         }
         ExecutableDeployItem::StoredContractByName { name, .. } => {
             vec![
                 // Session|Payment: by-name
-                Element::regular(&phase_label, "by-name".to_string()),
+                Element::regular(phase_label, "by-name".to_string()),
                 // Name: <name of the contract>
-                Element::regular("name", name.clone()),
+                Element::regular("Name", name.clone()),
             ]
         }
         ExecutableDeployItem::StoredVersionedContractByHash { hash, version, .. } => {
             vec![
                 // Session|Payment: by-hash-versioned
-                Element::regular(&phase_label, "by-hash-versioned".to_string()),
+                Element::regular(phase_label, "by-hash-versioned".to_string()),
                 // Address: <contract address>
-                Element::regular("address", hash.to_string()),
+                Element::regular("Address", hash.to_string()),
                 // Version: <version>
                 parse_version(version),
             ]
@@ -164,9 +271,9 @@ pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<E
         ExecutableDeployItem::StoredVersionedContractByName { name, version, .. } => {
             vec![
                 // Session|Payment: by-name-versioned
-                Element::regular(&phase_label, "by-name-versioned".to_string()),
+                Element::regular(phase_label, "by-name-versioned".to_string()),
                 // Name: <name of the contract>
-                Element::regular("name", name.to_string()),
+                Element::regular("Name", name.to_string()),
                 // Version: <version>
                 parse_version(version),
             ]
@@ -185,7 +292,7 @@ fn parse_version(version: &Option<u32>) -> Element {
         None => "latest".to_string(),
         Some(version) => format!("{}", version),
     };
-    Element::expert("version", version)
+    Element::expert("Version", version)
 }
 
 // Payment is a system type of payment when the `module_bytes` are empty.
@@ -193,51 +300,166 @@ fn is_system_payment(phase: TxnPhase, module_bytes: &Bytes) -> bool {
     phase.is_payment() && module_bytes.inner_bytes().is_empty()
 }
 
-fn remove_amount_arg(args: RuntimeArgs) -> RuntimeArgs {
-    let mut tree: BTreeMap<String, CLValue> = args.into();
-    tree.remove(mint::ARG_AMOUNT);
-    tree.into()
+/// Arguments that are used in the Transfer.
+const TRANSFER_ARGS: [&str; 5] = [ARG_TO, ARG_SOURCE, ARG_TARGET, mint::ARG_AMOUNT, ARG_ID];
+
+/// Returns the arguments other than the `used` ones, without copying them.
+fn extra_args<'a>(
+    args: &'a RuntimeArgs,
+    used: &'a [&str],
+) -> impl Iterator<Item = &'a NamedArg> + 'a {
+    args.named_args()
+        .filter(move |arg| !used.contains(&arg.name()))
 }
 
-/// Removes all arguments that are used in the Transfer.
-fn remove_transfer_args(args: RuntimeArgs) -> RuntimeArgs {
-    let mut tree: BTreeMap<String, CLValue> = args.into();
-    tree.remove(ARG_TO);
-    tree.remove(ARG_SOURCE);
-    tree.remove(ARG_TARGET);
-    tree.remove(mint::ARG_AMOUNT);
-    tree.remove(ARG_ID);
-    tree.into()
+/// Returns warnings about the extra arguments of a transfer that could be mistaken for its own –
+/// like `Target` next to `target`, or another amount of motes next to `amount`.
+/// Wallets displaying such arguments can be tricked into showing a different transfer.
+fn ambiguous_transfer_args(args: &RuntimeArgs) -> Vec<Element> {
+    let amount = args.get(mint::ARG_AMOUNT);
+    extra_args(args, &TRANSFER_ARGS)
+        .filter(|arg| {
+            TRANSFER_ARGS.contains(&arg.name().to_lowercase().as_str())
+                || (arg.cl_value().cl_type() == &CLType::U512 && Some(arg.cl_value()) != amount)
+        })
+        .map(|arg| Element::warning(format!("ambiguous argument {}", arg.name())))
+        .collect()
 }
 
-fn format_amount(motes: U512) -> String {
-    format!("{} motes", motes.separate_with_spaces())
+fn has_zero_amount(args: &RuntimeArgs) -> bool {
+    args.get(mint::ARG_AMOUNT)
+        .and_then(|cl_value| cl_value.clone().into_t::<U512>().ok())
+        .map_or(false, |amount| amount.is_zero())
 }
 
-pub(crate) fn parse_fee(args: &RuntimeArgs) -> Option<Element> {
-    parse_motes(args, "fee")
+pub(crate) fn parse_fee(args: &RuntimeArgs, currency: &Currency) -> Vec<Element> {
+    parse_motes(args, "Fee", currency)
 }
 
-pub(crate) fn parse_amount(args: &RuntimeArgs) -> Option<Element> {
-    parse_motes(args, "amount")
+pub(crate) fn parse_amount(args: &RuntimeArgs, currency: &Currency) -> Vec<Element> {
+    parse_motes(args, "Amount", currency)
 }
 
-fn parse_motes(args: &RuntimeArgs, ledger_label: &str) -> Option<Element> {
-    let f = |amount_str: String| {
-        let motes_amount = U512::from_dec_str(&amount_str).unwrap();
-        format_amount(motes_amount)
+// Amounts of any other type than `U512` are displayed raw,
+// with an expert element telling what their type is.
+fn parse_motes(
+    args: &RuntimeArgs,
+    ledger_label: &'static str,
+    currency: &Currency,
+) -> Vec<Element> {
+    let cl_value = match args.get(mint::ARG_AMOUNT) {
+        Some(cl_value) => cl_value,
+        None => return vec![],
     };
-    parse_optional_arg(args, mint::ARG_AMOUNT, ledger_label, false, f)
+    match cl_value.clone().into_t::<U512>() {
+        Ok(motes_amount) => vec![Element::regular(
+            ledger_label,
+            currency.format(motes_amount),
+        )],
+        Err(_) => {
+            let raw_value = cl_value_to_string(cl_value)
+                .unwrap_or_else(|_| hex::encode(cl_value.inner_bytes()));
+            vec![
+                Element::regular(ledger_label, raw_value).as_undecoded(),
+                Element::expert(
+                    format!("{} type", ledger_label),
+                    format!("{:?}, not U512", cl_value.cl_type()),
+                ),
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod transfer {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{runtime_args, AccessRights, RuntimeArgs, URef, U512};
+
+    use crate::{currency::Currency, ledger::TxnPhase};
+
+    use super::parse_generic_item;
+
+    #[test]
+    fn transfer_args_are_displayed_along_with_extra_args() {
+        let item = ExecutableDeployItem::Transfer {
+            args: runtime_args! {
+                "amount" => U512::from(1000u32),
+                "target" => URef::new([1u8; 32], AccessRights::READ),
+                "id" => 7u64,
+                "memo" => "extra".to_string(),
+            },
+        };
+        let elements =
+            parse_generic_item(&item, TxnPhase::Session, &[], &Currency::default()).unwrap();
+        let labels: Vec<&str> = elements.iter().map(|element| element.label()).collect();
+        assert_eq!(labels, ["Target", "Amount", "ID", "Args hash"]);
+        assert_eq!(elements[1].value(), "1 000 motes");
+        assert_eq!(elements[2].value(), "7");
+    }
+
+    #[test]
+    fn extra_args_resembling_transfer_args_are_warned_about() {
+        let item = ExecutableDeployItem::Transfer {
+            args: runtime_args! {
+                "amount" => U512::from(1000u32),
+                "target" => URef::new([1u8; 32], AccessRights::READ),
+                "id" => 7u64,
+                "Target" => URef::new([2u8; 32], AccessRights::READ),
+                "total" => U512::from(1u32),
+                "fee" => U512::from(1000u32),
+            },
+        };
+        let elements =
+            parse_generic_item(&item, TxnPhase::Session, &[], &Currency::default()).unwrap();
+        let warnings: Vec<&str> = elements
+            .iter()
+            .filter(|element| element.is_warning())
+            .map(|element| element.value())
+            .collect();
+        assert_eq!(
+            warnings,
+            ["ambiguous argument Target", "ambiguous argument total"]
+        );
+    }
+}
+
+#[cfg(test)]
+mod network {
+    use super::parse_network;
+
+    #[test]
+    fn public_networks_are_named() {
+        let testnet = parse_network("casper-test");
+        assert_eq!(testnet.value(), "Casper Testnet");
+        assert!(!testnet.is_expert());
+        let other = parse_network("casper-net-1");
+        assert_eq!(other.value(), "unknown chain");
+        assert!(other.is_expert());
+    }
 }
 
 #[cfg(test)]
 mod amount {
-    use casper_types::U512;
+    use casper_types::{runtime_args, RuntimeArgs, U512};
+
+    use crate::{currency::Currency, parser::deploy::parse_amount};
 
-    use crate::parser::deploy::format_amount;
+    #[test]
+    fn non_u512_amount_displayed_raw() {
+        let elements = parse_amount(
+            &runtime_args! { "amount" => 10000u64 },
+            &Currency::default(),
+        );
+        assert_eq!(elements[0].label(), "Amount");
+        assert_eq!(elements[0].value(), "10000");
+        assert_eq!(elements[1].label(), "Amount type");
+        assert_eq!(elements[1].value(), "U64, not U512");
+        assert!(elements[1].is_expert());
+    }
 
     #[test]
     fn amount_space_separated() {
+        let format_amount = |motes| Currency::default().format(motes);
         let one: U512 = 1u8.into();
         let expected = "1 motes".to_string();
         assert_eq!(expected, format_amount(one));
@@ -253,18 +475,121 @@ mod amount {
     }
 }
 
-pub(crate) fn identity<T>(el: T) -> T {
-    el
+/// Returns the number of approvals, followed by their signers – and, when `verify` is set,
+/// whether their signatures are valid for the deploy hash.
+pub(crate) fn parse_approvals(d: &Deploy, verify: bool) -> Result<Vec<Element>, ParseError> {
+    let approvals_count = d.approvals().len();
+    let signers_count = d
+        .approvals()
+        .iter()
+        .map(|approval| approval.signer())
+        .collect::<BTreeSet<_>>()
+        .len();
+    let mut elements = vec![
+        Element::expert("Approvals #", format!("{}", approvals_count)),
+        Element::expert("Signers #", format!("{}", signers_count)),
+    ];
+    // A key may approve the deploy more than once, with different signatures.
+    if signers_count < approvals_count {
+        elements.push(Element::warning("duplicate signers".to_string()));
+    }
+    // Signers of a multisig deploy, so that they can be reviewed one by one.
+    for (idx, approval) in d.approvals().iter().enumerate() {
+        elements.push(Element::expert(
+            format!("Signer {}", idx + 1),
+            parse_public_key(approval.signer())?,
+        ));
+        // The algorithm of the signature, which doesn't have to match the one of the key.
+        let algorithm = match approval.signature() {
+            Signature::Ed25519(_) => "ed25519",
+            Signature::Secp256k1(_) => "secp256k1",
+            // Only the system can sign with anything else.
+            _ => "system",
+        };
+        elements.push(Element::expert(
+            format!("Sig type {}", idx + 1),
+            algorithm.to_string(),
+        ));
+        if verify {
+            let validity = match crypto::verify(d.hash(), approval.signature(), approval.signer()) {
+                Ok(()) => "valid",
+                Err(_) => "INVALID",
+            };
+            elements.push(Element::expert(
+                format!("Approval {}", idx + 1),
+                validity.to_string(),
+            ));
+        }
+    }
+    Ok(elements)
 }
 
-pub(crate) fn parse_approvals(d: &Deploy) -> Vec<Element> {
-    let approvals_count = d.approvals().len();
-    vec![Element::expert(
-        "Approvals #",
-        format!("{}", approvals_count),
-    )]
+/// Returns the entry point and the arguments of a call of a stored contract.
+pub(crate) fn stored_contract_call(item: &ExecutableDeployItem) -> Option<(&str, &RuntimeArgs)> {
+    match item {
+        ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => None,
+        ExecutableDeployItem::StoredContractByHash {
+            entry_point, args, ..
+        }
+        | ExecutableDeployItem::StoredContractByName {
+            entry_point, args, ..
+        }
+        | ExecutableDeployItem::StoredVersionedContractByHash {
+            entry_point, args, ..
+        }
+        | ExecutableDeployItem::StoredVersionedContractByName {
+            entry_point, args, ..
+        } => Some((entry_point, args)),
+    }
 }
 
 fn entrypoint(entry_point: &str) -> Element {
-    Element::expert("entry-point", entry_point.to_string())
+    Element::expert("Entry-point", entry_point.to_string())
+}
+
+#[cfg(test)]
+mod approvals {
+    use casper_types::testing::TestRng;
+
+    use crate::test_data::deploy_samples;
+
+    use super::parse_approvals;
+
+    #[test]
+    fn duplicate_signers_are_warned_about() {
+        let mut rng = TestRng::new();
+        for sample in deploy_samples(&mut rng) {
+            let warned = parse_approvals(sample.sample(), false)
+                .unwrap()
+                .iter()
+                .any(|element| element.value() == "duplicate signers");
+            assert_eq!(warned, sample.label().contains("duplicate_signer"));
+        }
+    }
+
+    #[test]
+    fn signatures_of_other_deploys_are_invalid() {
+        let mut rng = TestRng::new();
+        for sample in deploy_samples(&mut rng)
+            .iter()
+            .filter(|sample| sample.label().starts_with("native_transfer"))
+        {
+            let mut validities: Vec<String> = parse_approvals(sample.sample(), true)
+                .unwrap()
+                .into_iter()
+                .filter(|element| element.label().starts_with("Approval "))
+                .map(|element| element.value().to_string())
+                .collect();
+            let expected: &[&str] = if sample.label().contains("bad_signature") {
+                &["INVALID"]
+            } else if sample.label().contains("duplicate_signer") {
+                &["INVALID", "valid"]
+            } else {
+                &["valid"]
+            };
+            validities.sort();
+            validities.dedup();
+            assert_eq!(validities, expected);
+        }
+    }
 }
@@ -0,0 +1,105 @@
+//! Calls of the testnet faucet contract, which sends motes to the `target` account –
+//! QA teams sign plenty of them while testing devices against testnet.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{
+    account::ACCOUNT_HASH_LENGTH,
+    system::mint::{ARG_AMOUNT, ARG_ID, ARG_TARGET},
+    CLType, RuntimeArgs,
+};
+
+use crate::{
+    currency::Currency,
+    error::ParseError,
+    ledger::{Element, TxnPhase},
+    parser::deploy::{deploy_type, parse_amount, stored_contract_call},
+};
+
+use super::runtime_args::parse_optional_arg;
+
+const FAUCET_ENTRYPOINTS: [&str; 2] = ["call_faucet", "faucet"];
+
+/// Returns `true` when the item calls the faucet's entry point with a `target`,
+/// and optionally the `amount` and `id` – but no other arguments.
+pub(crate) fn is_faucet(item: &ExecutableDeployItem) -> bool {
+    match stored_contract_call(item) {
+        Some((entry_point, args)) => {
+            FAUCET_ENTRYPOINTS.contains(&entry_point) && has_faucet_args(args)
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn parse_faucet(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let (entry_point, args) = match stored_contract_call(item) {
+        Some(call) => call,
+        None => return Ok(vec![]),
+    };
+    let mut elements = deploy_type(phase, item);
+    elements.push(Element::regular("Entry-point", entry_point.to_string()));
+    elements.extend(parse_optional_arg(args, ARG_TARGET, "Target", false, Ok)?);
+    // Faucets without the `amount` argument send the amount they're configured with.
+    elements.extend(parse_amount(args, currency));
+    elements.extend(parse_optional_arg(args, ARG_ID, "ID", true, Ok)?);
+    Ok(elements)
+}
+
+fn has_faucet_args(args: &RuntimeArgs) -> bool {
+    let target_types = [
+        CLType::ByteArray(ACCOUNT_HASH_LENGTH as u32),
+        CLType::PublicKey,
+    ];
+    args.get(ARG_TARGET)
+        .map_or(false, |target| target_types.contains(target.cl_type()))
+        && args.named_args().all(|arg| match arg.name() {
+            ARG_TARGET => true,
+            ARG_AMOUNT => arg.cl_value().cl_type() == &CLType::U512,
+            ARG_ID => arg.cl_value().cl_type() == &CLType::Option(Box::new(CLType::U64)),
+            _ => false,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{account::AccountHash, runtime_args, ContractHash, RuntimeArgs, U512};
+
+    use super::{is_faucet, parse_faucet};
+    use crate::{currency::Currency, ledger::TxnPhase};
+
+    fn call_faucet(args: RuntimeArgs) -> ExecutableDeployItem {
+        ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([1u8; 32]),
+            entry_point: "call_faucet".to_string(),
+            args,
+        }
+    }
+
+    #[test]
+    fn target_and_amount_are_regular() {
+        let item = call_faucet(runtime_args! {
+            "target" => AccountHash::new([2u8; 32]),
+            "amount" => U512::from(1_000_000_000_000u64),
+            "id" => Some(7u64),
+        });
+        assert!(is_faucet(&item));
+        let regular: Vec<_> = parse_faucet(&item, TxnPhase::Session, &Currency::default())
+            .unwrap()
+            .into_iter()
+            .filter(|element| !element.is_expert())
+            .map(|element| element.label().to_string())
+            .collect();
+        assert_eq!(
+            regular,
+            ["Execution", "Address", "Entry-point", "Target", "Amount"]
+        );
+        assert!(!is_faucet(&call_faucet(runtime_args! {
+            "target" => AccountHash::new([2u8; 32]),
+            "memo" => 1u64,
+        })));
+    }
+}
@@ -0,0 +1,87 @@
+//! Guesses about the calls of unrecognized contracts, for wallets experimenting
+//! with friendlier displays. Nothing guarantees the contract does what its arguments suggest,
+//! so the guesses come with a warning – and the Ledger app doesn't make them.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::ACCOUNT_HASH_LENGTH, CLType};
+use thousands::Separable;
+
+use crate::{error::ParseError, ledger::Element, parser::deploy::stored_contract_call};
+
+use super::runtime_args::parse_optional_arg;
+
+const RECIPIENT_ARG_KEY: &str = "recipient";
+const AMOUNT_ARG_KEY: &str = "amount";
+
+/// Returns the `recipient` and `amount` arguments of a stored contract call, labelled
+/// as a token transfer after a warning – if it has both of them, of the types of a transfer.
+pub(crate) fn token_transfer(item: &ExecutableDeployItem) -> Result<Vec<Element>, ParseError> {
+    let args = match stored_contract_call(item) {
+        Some((_, args)) => args,
+        None => return Ok(vec![]),
+    };
+    let recipient_types = [
+        CLType::Key,
+        CLType::PublicKey,
+        CLType::ByteArray(ACCOUNT_HASH_LENGTH as u32),
+    ];
+    let amount_types = [CLType::U64, CLType::U128, CLType::U256, CLType::U512];
+    let matches = args.get(RECIPIENT_ARG_KEY).map_or(false, |recipient| {
+        recipient_types.contains(recipient.cl_type())
+    }) && args
+        .get(AMOUNT_ARG_KEY)
+        .map_or(false, |amount| amount_types.contains(amount.cl_type()));
+    if !matches {
+        return Ok(vec![]);
+    }
+    let mut elements = vec![Element::warning("unverified contract".to_string())];
+    elements.extend(parse_optional_arg(
+        args,
+        RECIPIENT_ARG_KEY,
+        "Recipient",
+        false,
+        Ok,
+    )?);
+    // The decimals are known to the contract only, if it's a token at all.
+    elements.extend(parse_optional_arg(
+        args,
+        AMOUNT_ARG_KEY,
+        "Amount",
+        false,
+        |amount| Ok(format!("{} (token units?)", amount.separate_with_spaces())),
+    )?);
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+
+    use super::token_transfer;
+
+    #[test]
+    fn transfer_shaped_arguments_are_surfaced_with_a_warning() {
+        let call = |args: RuntimeArgs| ExecutableDeployItem::StoredContractByName {
+            name: "token".to_string(),
+            entry_point: "send".to_string(),
+            args,
+        };
+        let recipient = Key::Account(AccountHash::new([2u8; 32]));
+        let elements = token_transfer(&call(runtime_args! {
+            "recipient" => recipient,
+            "amount" => U256::from(1_000u64),
+            "memo" => "thanks".to_string(),
+        }))
+        .unwrap();
+        let labels: Vec<_> = elements.iter().map(|element| element.label()).collect();
+        assert_eq!(labels, ["Warning", "Recipient", "Amount"]);
+        assert_eq!(elements[2].value(), "1 000 (token units?)");
+        assert!(token_transfer(&call(runtime_args! {
+            "recipient" => recipient,
+            "amount" => "1000".to_string(),
+        }))
+        .unwrap()
+        .is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+//! Minimal reader of the wasm modules sent as module bytes – just enough to list their exports,
+//! so that reviewers of contract installs get more than the hash of the code.
+
+use crate::ledger::Element;
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const WASM_VERSION: &[u8] = &[1, 0, 0, 0];
+const EXPORT_SECTION_ID: u8 = 7;
+const FUNCTION_EXPORT_KIND: u8 = 0;
+
+/// Returns the expert elements describing the module: its size and exported functions.
+pub(crate) fn describe_module(module: &[u8]) -> Vec<Element> {
+    let exports = match exported_functions(module) {
+        Some(names) if names.is_empty() => "none".to_string(),
+        Some(names) => names.join(", "),
+        None => "not a wasm module".to_string(),
+    };
+    vec![
+        Element::expert("Module size", format!("{} bytes", module.len())),
+        Element::expert("Exports", exports),
+    ]
+}
+
+/// Names of the functions exported by the module, in the order of declaration,
+/// or `None` if it isn't a well-formed wasm module.
+fn exported_functions(module: &[u8]) -> Option<Vec<String>> {
+    let mut reader = Reader(module);
+    if reader.bytes(4)? != WASM_MAGIC || reader.bytes(4)? != WASM_VERSION {
+        return None;
+    }
+    let mut names = vec![];
+    while !reader.0.is_empty() {
+        let id = reader.byte()?;
+        let size = reader.leb_u32()? as usize;
+        let mut section = Reader(reader.bytes(size)?);
+        if id != EXPORT_SECTION_ID {
+            continue;
+        }
+        for _ in 0..section.leb_u32()? {
+            let name_len = section.leb_u32()? as usize;
+            let name = std::str::from_utf8(section.bytes(name_len)?).ok()?;
+            let kind = section.byte()?;
+            let _index = section.leb_u32()?;
+            if kind == FUNCTION_EXPORT_KIND {
+                names.push(name.to_string());
+            }
+        }
+    }
+    Some(names)
+}
+
+// Cursor over the bytes of the module; every read fails on truncated input.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, count: usize) -> Option<&'a [u8]> {
+        if count > self.0.len() {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(count);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.bytes(1).map(|bytes| bytes[0])
+    }
+
+    // Unsigned LEB128, of at most 5 bytes.
+    fn leb_u32(&mut self) -> Option<u32> {
+        let mut value = 0u32;
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            value |= u32::from(byte & 0x7f).checked_shl(shift)?;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exported_functions;
+
+    #[test]
+    fn lists_exported_functions() {
+        let mut module = b"\0asm\x01\0\0\0".to_vec();
+        // Type section, skipped.
+        module.extend_from_slice(&[1, 4, 1, 0x60, 0, 0]);
+        // Exports of the `call` function and of a memory.
+        let mut exports = vec![2, 4];
+        exports.extend_from_slice(b"call");
+        exports.extend_from_slice(&[0, 0, 6]);
+        exports.extend_from_slice(b"memory");
+        exports.extend_from_slice(&[2, 0]);
+        module.extend_from_slice(&[7, exports.len() as u8]);
+        module.extend_from_slice(&exports);
+        assert_eq!(exported_functions(&module), Some(vec!["call".to_string()]));
+        assert_eq!(exported_functions(&module[..module.len() - 1]), None);
+        assert_eq!(exported_functions(&[0xde, 0xad]), None);
+    }
+}
@@ -1,26 +1,58 @@
+use crate::currency::Currency;
+use crate::error::ParseError;
 use crate::ledger::{Element, TxnPhase};
+use crate::policy::{self, ArgPolicy, Visibility};
 use crate::utils::cl_value_to_string;
 use casper_types::bytesrepr::ToBytes;
-use casper_types::system::mint::{ARG_ID, ARG_SOURCE, ARG_TARGET, ARG_TO};
-use casper_types::RuntimeArgs;
+use casper_types::system::mint::{ARG_AMOUNT, ARG_ID, ARG_SOURCE, ARG_TARGET, ARG_TO};
+use casper_types::{CLType, RuntimeArgs};
 
-use super::deploy::{identity, parse_amount};
+use super::deploy::parse_amount;
 
 /// Parses all contract arguments into a form:
 /// arg-n-name: <name>
 /// arg-n-val: <val>
 /// where n is the ordinal number of the argument.
-pub(crate) fn parse_runtime_args(phase: &TxnPhase, ra: &RuntimeArgs) -> Vec<Element> {
+///
+/// The arguments the `policy` displays follow the digest of them, in the order of the policy.
+pub(crate) fn parse_runtime_args(
+    phase: &TxnPhase,
+    ra: &RuntimeArgs,
+    policy: &[ArgPolicy],
+) -> Result<Vec<Element>, ParseError> {
     let mut elements: Vec<Element> = vec![];
     if !ra.is_empty() {
-        let args_digest =
-            casper_hashing::Digest::hash(ToBytes::to_bytes(ra).expect("ToBytes to work."));
+        let args_bytes = ToBytes::to_bytes(ra).map_err(|err| {
+            ParseError::InvalidValue(format!("failed to serialize the arguments: {}", err))
+        })?;
+        let args_digest = casper_hashing::Digest::hash(args_bytes);
         let args_hash = base16::encode_lower(&args_digest);
         elements.push(Element::regular(
-            "args hash",
+            "Args hash",
             format!("{}-{}", phase.to_string().to_lowercase(), args_hash),
         ));
     }
+    for arg in policy {
+        // Hidden arguments have no element of their own, the digest covers them anyway.
+        let expert = match arg.visibility() {
+            Visibility::Regular => false,
+            Visibility::Expert => true,
+            Visibility::Hidden => continue,
+        };
+        if let Some(cl_value) = ra.get(arg.name()) {
+            let value =
+                cl_value_to_string(cl_value).map_err(|err| ParseError::InvalidArgument {
+                    name: arg.name().to_string(),
+                    reason: err.to_string(),
+                })?;
+            let label = arg.label().to_string();
+            elements.push(if expert {
+                Element::expert(label, value)
+            } else {
+                Element::regular(label, value)
+            });
+        }
+    }
 
     // NOTE: The code that follows would iterate over all args and parse them
     // for Ledger presentation in a following format:
@@ -37,27 +69,32 @@ pub(crate) fn parse_runtime_args(phase: &TxnPhase, ra: &RuntimeArgs) -> Vec<Elem
     //     let value_str = cl_value_to_string(value);
     //     elements.push(Element::expert(&value_label, value_str));
     // }
-    elements
+    Ok(elements)
 }
 
-pub(crate) fn parse_optional_arg<F: Fn(String) -> String>(
+pub(crate) fn parse_optional_arg<F: Fn(String) -> Result<String, ParseError>>(
     args: &RuntimeArgs,
     key: &str,
-    label: &str,
+    label: &'static str,
     expert: bool,
     f: F,
-) -> Option<Element> {
+) -> Result<Option<Element>, ParseError> {
     match args.get(key) {
         Some(cl_value) => {
-            let value = f(cl_value_to_string(cl_value));
+            let value = cl_value_to_string(cl_value).and_then(f).map_err(|err| {
+                ParseError::InvalidArgument {
+                    name: key.to_string(),
+                    reason: err.to_string(),
+                }
+            })?;
             let element = if expert {
                 Element::expert(label, value)
             } else {
                 Element::regular(label, value)
             };
-            Some(element)
+            Ok(Some(element))
         }
-        None => None,
+        None => Ok(None),
     }
 }
 
@@ -67,15 +104,52 @@ pub(crate) fn parse_optional_arg<F: Fn(String) -> String>(
 /// * ID
 /// Optional fields:
 /// * source
-pub(crate) fn parse_transfer_args(args: &RuntimeArgs) -> Vec<Element> {
-    let mut elements: Vec<Element> = parse_optional_arg(args, ARG_TO, "recipient", false, identity)
-        .into_iter()
-        .collect();
-    elements.extend(parse_optional_arg(args, ARG_SOURCE, "from", true, identity));
-    elements.extend(parse_optional_arg(
-        args, ARG_TARGET, "target", false, identity,
+///
+/// A missing target or amount is displayed as a warning, rather than silently omitted.
+/// The arguments listed in the `policy` are displayed (or hidden) as it says.
+pub(crate) fn parse_transfer_args(
+    args: &RuntimeArgs,
+    policy: &[ArgPolicy],
+    currency: &Currency,
+) -> Result<Vec<Element>, ParseError> {
+    let with_policy =
+        |key: &str, element: Option<Element>| element.and_then(|e| policy::apply(policy, key, e));
+    let mut elements: Vec<Element> = with_policy(
+        ARG_TO,
+        parse_optional_arg(args, ARG_TO, "Recipient", false, Ok)?,
+    )
+    .into_iter()
+    .collect();
+    elements.extend(with_policy(
+        ARG_SOURCE,
+        parse_optional_arg(args, ARG_SOURCE, "From", true, Ok)?,
     ));
-    elements.extend(parse_amount(args));
-    elements.extend(parse_optional_arg(args, ARG_ID, "ID", true, identity));
-    elements
+    match parse_optional_arg(args, ARG_TARGET, "Target", false, Ok)? {
+        Some(target) => elements.extend(with_policy(ARG_TARGET, Some(target))),
+        None => elements.push(missing_argument(ARG_TARGET)),
+    }
+    let amount = parse_amount(args, currency);
+    if amount.is_empty() {
+        elements.push(missing_argument(ARG_AMOUNT));
+    }
+    elements.extend(
+        amount
+            .into_iter()
+            .filter_map(|element| policy::apply(policy, ARG_AMOUNT, element)),
+    );
+    elements.extend(with_policy(
+        ARG_ID,
+        parse_optional_arg(args, ARG_ID, "ID", true, Ok)?,
+    ));
+    Ok(elements)
+}
+
+/// Returns `true` when the argument is present, with the expected type.
+pub(crate) fn has_arg_of_type(args: &RuntimeArgs, key: &str, cl_type: &CLType) -> bool {
+    args.get(key)
+        .map_or(false, |cl_value| cl_value.cl_type() == cl_type)
+}
+
+fn missing_argument(name: &str) -> Element {
+    Element::warning(format!("missing {}", name))
 }
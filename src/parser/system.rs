@@ -0,0 +1,48 @@
+//! Calls of contracts by the names of the mint and handle payment system contracts.
+//!
+//! The name resolves through the named keys of the signer's account, so any contract
+//! stored under `mint` is called this way – such calls are displayed as generic ones,
+//! with a warning rather than as calls of the system contracts.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+
+const SYSTEM_CONTRACT_NAMES: [&str; 2] = ["mint", "handle_payment"];
+
+/// Returns `true` when the item calls a contract by the name of a system contract.
+pub(crate) fn is_named_as_system_contract(item: &ExecutableDeployItem) -> bool {
+    match item {
+        ExecutableDeployItem::StoredContractByName { name, .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { name, .. } => {
+            SYSTEM_CONTRACT_NAMES.contains(&name.as_str())
+        }
+        ExecutableDeployItem::ModuleBytes { .. }
+        | ExecutableDeployItem::StoredContractByHash { .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { .. }
+        | ExecutableDeployItem::Transfer { .. } => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{runtime_args, ContractHash, RuntimeArgs, U512};
+
+    use super::is_named_as_system_contract;
+
+    #[test]
+    fn only_calls_by_name_are_matched() {
+        let args = runtime_args! { "amount" => U512::one() };
+        let by_name = ExecutableDeployItem::StoredContractByName {
+            name: "mint".to_string(),
+            entry_point: "mint".to_string(),
+            args: args.clone(),
+        };
+        let by_hash = ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([1u8; 32]),
+            entry_point: "mint".to_string(),
+            args,
+        };
+        assert!(is_named_as_system_contract(&by_name));
+        assert!(!is_named_as_system_contract(&by_hash));
+    }
+}
@@ -1,14 +1,71 @@
 use casper_types::Timestamp;
+use std::convert::TryFrom;
 use std::time::{Duration, SystemTime};
 
+use crate::error::ParseError;
+
 // Ledger/Zondax supports timestamps only up to seconds resolution.
 // `Display` impl for the `Timestamp` in the casper-node crate uses milliseconds-resolution
 // so we need a custom implementation for the timestamp representation.
-pub(crate) fn timestamp_to_seconds_res(timestamp: Timestamp) -> String {
+pub(crate) fn timestamp_to_seconds_res(timestamp: Timestamp) -> Result<String, ParseError> {
     let system_time = SystemTime::UNIX_EPOCH
         .checked_add(Duration::from_millis(timestamp.millis()))
-        .expect("should be within system time limits");
-    format!("{}", humantime::format_rfc3339_seconds(system_time))
+        .ok_or_else(|| {
+            ParseError::InvalidValue(format!("timestamp {} is out of range", timestamp.millis()))
+        })?;
+    Ok(format!(
+        "{}",
+        humantime::format_rfc3339_seconds(system_time)
+    ))
+}
+
+/// Formats the timestamp in the time zone `offset_minutes` east of UTC,
+/// like `2021-05-04T16:20:35+02:00` – to compare with explorers showing local times.
+pub(crate) fn timestamp_with_offset(
+    timestamp: Timestamp,
+    offset_minutes: i32,
+) -> Result<String, ParseError> {
+    let offset_millis = i64::from(offset_minutes) * 60 * 1000;
+    let local_millis = i64::try_from(timestamp.millis())
+        .ok()
+        .and_then(|millis| millis.checked_add(offset_millis))
+        .and_then(|millis| u64::try_from(millis).ok())
+        .ok_or_else(|| {
+            ParseError::InvalidValue(format!("timestamp {} is out of range", timestamp.millis()))
+        })?;
+    let utc = timestamp_to_seconds_res(Timestamp::from(local_millis))?;
+    let sign = if offset_minutes < 0 { '-' } else { '+' };
+    let offset = offset_minutes.unsigned_abs();
+    Ok(format!(
+        "{}{}{:02}:{:02}",
+        utc.trim_end_matches('Z'),
+        sign,
+        offset / 60,
+        offset % 60
+    ))
+}
+
+#[cfg(test)]
+mod offset_tests {
+    use std::str::FromStr;
+
+    use casper_types::Timestamp;
+
+    use super::timestamp_with_offset;
+
+    #[test]
+    fn local_time_is_followed_by_the_offset() {
+        let timestamp = Timestamp::from_str("2021-05-04T14:20:35.104Z").unwrap();
+        assert_eq!(
+            timestamp_with_offset(timestamp, 120).unwrap(),
+            "2021-05-04T16:20:35+02:00"
+        );
+        assert_eq!(
+            timestamp_with_offset(timestamp, -330).unwrap(),
+            "2021-05-04T08:50:35-05:30"
+        );
+        assert!(timestamp_with_offset(Timestamp::zero(), -60).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,179 @@
+//! Visibility of the arguments of entry points, set by the integrators – so that
+//! the arguments critical to their business are displayed in regular mode, without forking the crate.
+//!
+//! ```toml
+//! [[arg]]
+//! # Native transfers are the `transfer` entry point, session code the `call` one.
+//! entry_point = "transfer"
+//! name = "id"
+//! # One of `regular`, `expert` or `hidden`.
+//! visibility = "regular"
+//!
+//! [[arg]]
+//! entry_point = "swap"
+//! name = "recipient"
+//! visibility = "regular"
+//! # Optional: the name of the argument is displayed by default.
+//! label = "Recipient"
+//! ```
+
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use serde::Deserialize;
+
+use crate::{
+    ledger::{is_printable, Element, LEDGER_VIEW_NAME_CHAR_COUNT},
+    parser::deploy::stored_contract_call,
+};
+
+// Entry points of the items that don't name one.
+const TRANSFER_ENTRYPOINT: &str = "transfer";
+const SESSION_ENTRYPOINT: &str = "call";
+
+/// Visibility of the arguments, by entry point – read from a TOML or JSON file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "PolicyFile")]
+pub struct Policy {
+    entry_points: BTreeMap<String, Vec<ArgPolicy>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(default, rename = "arg")]
+    args: Vec<ArgPolicy>,
+}
+
+/// Visibility of an argument of the entry point.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ArgPolicy {
+    entry_point: String,
+    name: String,
+    visibility: Visibility,
+    /// Label of the argument, if not its name.
+    #[serde(default)]
+    label: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Visibility {
+    Regular,
+    Expert,
+    Hidden,
+}
+
+impl TryFrom<PolicyFile> for Policy {
+    type Error = String;
+
+    fn try_from(file: PolicyFile) -> Result<Self, Self::Error> {
+        let mut entry_points: BTreeMap<String, Vec<ArgPolicy>> = BTreeMap::new();
+        for arg in file.args {
+            let label = arg.label();
+            if label.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT || !is_printable(label) {
+                return Err(format!(
+                    "label {} of {} isn't printable in {} characters",
+                    label, arg.entry_point, LEDGER_VIEW_NAME_CHAR_COUNT
+                ));
+            }
+            let args = entry_points.entry(arg.entry_point.clone()).or_default();
+            if args.iter().any(|listed| listed.name == arg.name) {
+                return Err(format!(
+                    "{} of {} is listed more than once",
+                    arg.name, arg.entry_point
+                ));
+            }
+            args.push(arg);
+        }
+        Ok(Policy { entry_points })
+    }
+}
+
+impl Policy {
+    /// Reads the policy from JSON, of the same structure as the TOML file.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads the policy from the TOML (or, with the `.json` extension, JSON) file.
+    #[cfg(feature = "cli")]
+    pub(crate) fn read(path: &std::path::Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        if path.extension().map_or(false, |ext| ext == "json") {
+            Self::from_json(&content).map_err(|err| err.to_string())
+        } else {
+            toml::from_str(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    /// Returns the policy of the arguments of the entry point called by the item.
+    pub(crate) fn args(&self, item: &ExecutableDeployItem) -> &[ArgPolicy] {
+        let entry_point = match item {
+            ExecutableDeployItem::ModuleBytes { .. } => SESSION_ENTRYPOINT,
+            ExecutableDeployItem::Transfer { .. } => TRANSFER_ENTRYPOINT,
+            _ => stored_contract_call(item).map_or("", |(entry_point, _)| entry_point),
+        };
+        self.entry_points
+            .get(entry_point)
+            .map_or(&[], |args| args.as_slice())
+    }
+}
+
+impl ArgPolicy {
+    /// Name of the argument.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Label of the argument – its name, unless the policy sets one.
+    pub(crate) fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
+    pub(crate) fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+}
+
+/// Applies the policy of the argument `key` to its element, if the policy lists it:
+/// hides it, or displays it in the set mode – with the set label, if any.
+pub(crate) fn apply(policy: &[ArgPolicy], key: &str, element: Element) -> Option<Element> {
+    let arg = match policy.iter().find(|arg| arg.name == key) {
+        Some(arg) => arg,
+        None => return Some(element),
+    };
+    let label = arg
+        .label
+        .clone()
+        .unwrap_or_else(|| element.label().to_string());
+    match arg.visibility {
+        Visibility::Regular => Some(Element::regular(label, element.value().to_string())),
+        Visibility::Expert => Some(Element::expert(label, element.value().to_string())),
+        Visibility::Hidden => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Policy, Visibility};
+
+    #[test]
+    fn labels_must_fit_the_display() {
+        let policy = Policy::from_json(
+            r#"{"arg": [{ "entry_point": "swap", "name": "recipient", "visibility": "regular" }]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            policy.entry_points["swap"][0].visibility(),
+            Visibility::Regular
+        );
+        assert!(Policy::from_json(
+            r#"{"arg": [{ "entry_point": "swap", "name": "token_recipient", "visibility": "expert" }]}"#,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,50 @@
+//! Extension point for decoders of contracts this crate doesn't know,
+//! registered with [`Options::recognizer`](crate::Options::recognizer).
+
+use std::fmt::Debug;
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+
+use crate::ledger::Element;
+
+/// Decoder of the payment or session items calling a particular contract.
+///
+/// The header and the approvals of the deploy are rendered as usual –
+/// only the elements of the recognized item are replaced.
+pub trait DeployRecognizer: Debug + Send + Sync {
+    /// Returns the elements displayed for the item,
+    /// or `None` when the item isn't a call this recognizer decodes.
+    fn recognize(&self, item: &ExecutableDeployItem) -> Option<Vec<Element>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::testing::TestRng;
+
+    use super::DeployRecognizer;
+    use crate::{deploy_to_elements, ledger::Element, test_data::deploy_samples, Options};
+
+    #[derive(Debug)]
+    struct Everything;
+
+    impl DeployRecognizer for Everything {
+        fn recognize(&self, _item: &ExecutableDeployItem) -> Option<Vec<Element>> {
+            Some(vec![Element::regular("Custom", "call".to_string())])
+        }
+    }
+
+    #[test]
+    fn recognized_items_keep_the_header_and_approvals() {
+        let mut rng = TestRng::new();
+        let sample = deploy_samples(&mut rng).remove(0);
+        let options = Options::default().recognizer(Arc::new(Everything));
+        let elements = deploy_to_elements(sample.sample(), &options).unwrap();
+        let labels: Vec<_> = elements.iter().map(|element| element.label()).collect();
+        assert_eq!(labels.iter().filter(|label| **label == "Custom").count(), 2);
+        assert!(labels.contains(&"Chain ID"));
+        assert!(labels.contains(&"Approvals #"));
+    }
+}
@@ -0,0 +1,85 @@
+//! Redaction of the displayed values, for services that must log what was displayed
+//! without storing the amounts and addresses themselves.
+
+use casper_hashing::Digest;
+
+use crate::{error::ParseError, ledger::Element};
+
+/// Elements of a stream with their values replaced by the digests of them –
+/// so that a logged element can be matched against a known value, but not read.
+///
+/// Values like amounts can be guessed by hashing the likely ones, so the digests
+/// should be salted with a secret of the service.
+#[derive(Debug, Clone)]
+pub struct Redacted<I> {
+    elements: I,
+    salt: Vec<u8>,
+}
+
+impl<I> Redacted<I>
+where
+    I: Iterator<Item = Result<Element, ParseError>>,
+{
+    /// Redacts the elements of the stream, like [`deploy_to_element_stream`](crate::deploy_to_element_stream).
+    pub fn new(elements: I) -> Self {
+        Redacted {
+            elements,
+            salt: vec![],
+        }
+    }
+
+    /// Prepends the `salt` to the values before hashing them.
+    pub fn salted(mut self, salt: Vec<u8>) -> Self {
+        self.salt = salt;
+        self
+    }
+}
+
+impl<I> Iterator for Redacted<I>
+where
+    I: Iterator<Item = Result<Element, ParseError>>,
+{
+    type Item = Result<Element, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let salt = &self.salt;
+        self.elements
+            .next()
+            .map(|element| element.map(|element| redacted(element, salt)))
+    }
+}
+
+// Replaces the value of the element by the hex-encoded digest of the salted value.
+fn redacted(element: Element, salt: &[u8]) -> Element {
+    let mut salted = salt.to_vec();
+    salted.extend_from_slice(element.value().as_bytes());
+    let digest = base16::encode_lower(&Digest::hash(salted));
+    element.with_value(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Redacted;
+    use crate::ledger::Element;
+
+    #[test]
+    fn values_are_replaced_by_salted_digests() {
+        let elements = || {
+            vec![
+                Ok(Element::regular("Amount", "10 motes".to_string())),
+                Ok(Element::expert("Amount", "10 motes".to_string())),
+            ]
+            .into_iter()
+        };
+        let redacted: Vec<Element> = Redacted::new(elements()).map(Result::unwrap).collect();
+        assert_eq!(redacted[0].label(), "Amount");
+        assert_ne!(redacted[0].value(), "10 motes");
+        assert_eq!(redacted[0].value(), redacted[1].value());
+        assert!(redacted[1].is_expert());
+        let salted: Vec<Element> = Redacted::new(elements())
+            .salted(b"secret".to_vec())
+            .map(Result::unwrap)
+            .collect();
+        assert_ne!(salted[0].value(), redacted[0].value());
+    }
+}
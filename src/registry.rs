@@ -0,0 +1,195 @@
+//! Registry of known contracts, so that calls of them are displayed with a friendly name
+//! and readable argument labels – without hardcoding any contract in this crate.
+//!
+//! ```toml
+//! [[contract]]
+//! # Contract hash, or package hash for versioned calls.
+//! hash = "hash-8a3b0c9d..."
+//! name = "CSPR.market"
+//!
+//! # Arguments displayed for the entry point, with their labels.
+//! [contract.entry_points.buy]
+//! token_id = "Token"
+//! price = "Price"
+//! ```
+
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use serde::Deserialize;
+
+use crate::{
+    error::ParseError,
+    ledger::{is_printable, Element, LEDGER_VIEW_NAME_CHAR_COUNT},
+    parser::deploy::stored_contract_call,
+    utils::cl_value_to_string,
+};
+
+// Prefixes of the formatted contract and package hashes.
+const HASH_PREFIXES: [&str; 3] = ["hash-", "contract-package-", "contract-"];
+
+/// Contracts known by their hashes, read from a TOML or JSON file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(try_from = "RegistryFile")]
+pub struct Registry {
+    /// Known contracts by their (hex-encoded) hash.
+    contracts: BTreeMap<String, KnownContract>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegistryFile {
+    #[serde(default, rename = "contract")]
+    contracts: Vec<KnownContract>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KnownContract {
+    hash: String,
+    name: String,
+    /// Labels of the displayed arguments, by entry point and argument name.
+    #[serde(default)]
+    entry_points: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl TryFrom<RegistryFile> for Registry {
+    type Error = String;
+
+    fn try_from(file: RegistryFile) -> Result<Self, Self::Error> {
+        let mut contracts = BTreeMap::new();
+        for contract in file.contracts {
+            let hash = normalized_hash(&contract.hash)?;
+            if !is_printable(&contract.name) {
+                return Err(format!("name of {} isn't printable", contract.hash));
+            }
+            for label in contract
+                .entry_points
+                .values()
+                .flat_map(|args| args.values())
+            {
+                if label.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT || !is_printable(label) {
+                    return Err(format!(
+                        "label {} of {} isn't printable in {} characters",
+                        label, contract.hash, LEDGER_VIEW_NAME_CHAR_COUNT
+                    ));
+                }
+            }
+            if contracts.insert(hash, contract.clone()).is_some() {
+                return Err(format!("{} is listed more than once", contract.hash));
+            }
+        }
+        Ok(Registry { contracts })
+    }
+}
+
+/// Returns the hex-encoded hash, without any prefix.
+pub(crate) fn normalized_hash(hash: &str) -> Result<String, String> {
+    let hex_hash = HASH_PREFIXES
+        .iter()
+        .find_map(|prefix| hash.strip_prefix(prefix))
+        .unwrap_or(hash)
+        .to_lowercase();
+    match hex::decode(&hex_hash) {
+        Ok(bytes) if bytes.len() == 32 => Ok(hex_hash),
+        _ => Err(format!("{} isn't a 32-byte hex-encoded hash", hash)),
+    }
+}
+
+impl Registry {
+    /// Reads the registry from JSON, of the same structure as the TOML file.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads the registry from the TOML (or, with the `.json` extension, JSON) file.
+    #[cfg(feature = "cli")]
+    pub(crate) fn read(path: &std::path::Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        if path.extension().map_or(false, |ext| ext == "json") {
+            Self::from_json(&content).map_err(|err| err.to_string())
+        } else {
+            toml::from_str(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    /// Returns the elements naming the called contract, if it's known,
+    /// followed by the arguments listed for its entry point – in the order of the call.
+    pub(crate) fn elements(&self, item: &ExecutableDeployItem) -> Result<Vec<Element>, ParseError> {
+        let (hash, (entry_point, args)) = match (called_contract(item), stored_contract_call(item))
+        {
+            (Some(hash), Some(call)) => (hash, call),
+            _ => return Ok(vec![]),
+        };
+        let contract = match self.contracts.get(&hex::encode(hash)) {
+            Some(contract) => contract,
+            None => return Ok(vec![]),
+        };
+        let mut elements = vec![Element::regular("Contract", contract.name.clone())];
+        if let Some(labels) = contract.entry_points.get(entry_point) {
+            for arg in args.named_args() {
+                if let Some(label) = labels.get(arg.name()) {
+                    let value = cl_value_to_string(arg.cl_value()).map_err(|err| {
+                        ParseError::InvalidArgument {
+                            name: arg.name().to_string(),
+                            reason: err.to_string(),
+                        }
+                    })?;
+                    elements.push(Element::regular(label.clone(), value));
+                }
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Returns `true` when the called contract is known, with the entry point listed for it.
+    pub(crate) fn knows_entry_point(&self, item: &ExecutableDeployItem) -> bool {
+        match (called_contract(item), stored_contract_call(item)) {
+            (Some(hash), Some((entry_point, _))) => self
+                .contracts
+                .get(&hex::encode(hash))
+                .map_or(false, |contract| {
+                    contract.entry_points.contains_key(entry_point)
+                }),
+            _ => false,
+        }
+    }
+}
+
+/// Returns the hash of the contract (or package, for versioned calls) called by hash.
+///
+/// Calls by name depend on the named keys of the account, so they can't be resolved.
+pub(crate) fn called_contract(item: &ExecutableDeployItem) -> Option<[u8; 32]> {
+    match item {
+        ExecutableDeployItem::StoredContractByHash { hash, .. } => Some(hash.value()),
+        ExecutableDeployItem::StoredVersionedContractByHash { hash, .. } => Some(hash.value()),
+        ExecutableDeployItem::ModuleBytes { .. }
+        | ExecutableDeployItem::StoredContractByName { .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { .. }
+        | ExecutableDeployItem::Transfer { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+
+    #[test]
+    fn rejects_labels_the_device_cant_display() {
+        let registry = |label: &str| {
+            Registry::from_json(&format!(
+                r#"{{"contract": [{{
+                    "hash": "hash-{}",
+                    "name": "Market",
+                    "entry_points": {{ "buy": {{ "price": "{}" }} }}
+                }}]}}"#,
+                "ab".repeat(32),
+                label
+            ))
+        };
+        assert!(registry("Price").is_ok());
+        assert!(registry("Price of the token").is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use casper_node::types::Deploy;
+use serde_json::{json, Value};
+
+use crate::import;
+
+/// Fetches the deploy with the given hash from the node's JSON-RPC API.
+///
+/// Nodes before 2.0 are queried with `info_get_deploy`, newer ones with `info_get_transaction`.
+pub(crate) fn get_deploy(node_url: &str, hash: &str) -> Result<Deploy, String> {
+    let response = call(node_url, "info_get_deploy", json!({ "deploy_hash": hash }))
+        .or_else(|_| {
+            call(
+                node_url,
+                "info_get_transaction",
+                json!({ "transaction_hash": { "Deploy": hash } }),
+            )
+        })
+        .or_else(|_| {
+            // Found only to report that `TransactionV1` isn't supported.
+            call(
+                node_url,
+                "info_get_transaction",
+                json!({ "transaction_hash": { "Version1": hash } }),
+            )
+        })?;
+    import::deploy_from_value(response)
+}
+
+fn call(node_url: &str, method: &str, params: Value) -> Result<Value, String> {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    });
+    let response: Value = ureq::post(&rpc_endpoint(node_url))
+        .send_json(request)
+        .map_err(|err| format!("{} failed: {}", method, err))?
+        .into_json()
+        .map_err(|err| format!("{} failed: {}", method, err))?;
+    match response.get("error") {
+        Some(error) => Err(format!("{} failed: {}", method, error)),
+        None => Ok(response),
+    }
+}
+
+// Nodes serve the API under `/rpc`, which users tend to omit.
+fn rpc_endpoint(node_url: &str) -> String {
+    let node_url = node_url.trim_end_matches('/');
+    if node_url.ends_with("/rpc") {
+        node_url.to_string()
+    } else {
+        format!("{}/rpc", node_url)
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// A generic wrapper around any type `T` that can be considered as being a sample test vector.
 /// It has associated `label` that described the sample and validity flag (`valid`)
 /// indicating whether the sample is correct - i.e. whether it is a valid CasperNetwork transaction.
@@ -6,6 +8,7 @@ pub(crate) struct Sample<V> {
     label: String,
     sample: V,
     valid: bool,
+    displayable: bool,
 }
 
 impl<V> Sample<V> {
@@ -14,14 +17,70 @@ impl<V> Sample<V> {
             label: label.into(),
             sample,
             valid,
+            displayable: true,
         }
     }
 
+    /// Marks the sample as one the device can't display, so that it's signed blindly.
+    pub(crate) fn non_displayable(mut self) -> Sample<V> {
+        self.displayable = false;
+        self
+    }
+
     pub(crate) fn destructure(self) -> (String, V, bool) {
         (self.label, self.sample, self.valid)
     }
 
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    pub(crate) fn is_displayable(&self) -> bool {
+        self.displayable
+    }
+
+    /// Returns reference to the underlying sample.
+    pub(crate) fn sample(&self) -> &V {
+        &self.sample
+    }
+
     pub(crate) fn add_label(&mut self, label: String) {
         self.label = format!("{}__{}", self.label, label);
     }
 }
+
+/// Assigns samples identifiers that don't depend on their position in the whole corpus.
+///
+/// The identifier is the sample's label followed by the number of the samples with the same
+/// label generated before it, so it is determined only by the builder that produced the sample.
+/// Adding a new sample therefore doesn't change identifiers of the other builders' samples.
+#[derive(Default)]
+pub(crate) struct StableIds {
+    occurrences: HashMap<String, usize>,
+}
+
+impl StableIds {
+    pub(crate) fn assign(&mut self, label: &str) -> String {
+        let occurrence = self.occurrences.entry(label.to_string()).or_insert(0);
+        let id = format!("{}-{}", label, occurrence);
+        *occurrence += 1;
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StableIds;
+
+    #[test]
+    fn stable_ids_are_counted_per_label() {
+        let mut ids = StableIds::default();
+        assert_eq!(ids.assign("transfer"), "transfer-0");
+        assert_eq!(ids.assign("delegate"), "delegate-0");
+        assert_eq!(ids.assign("transfer"), "transfer-1");
+    }
+}
@@ -3,8 +3,8 @@ use std::str::FromStr;
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_node::types::{Deploy, DeployHash};
 use casper_types::{
-    account::AccountHash, AccessRights, AsymmetricType, CLValue, Key, PublicKey, RuntimeArgs,
-    SecretKey, TimeDiff, Timestamp, URef, U512,
+    account::AccountHash, bytesrepr::Bytes, runtime_args, AccessRights, AsymmetricType, CLValue,
+    Key, PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, URef, U512,
 };
 use rand::{prelude::*, Rng};
 
@@ -12,13 +12,21 @@ use auction::{delegate, undelegate};
 
 use crate::sample::Sample;
 
-use self::{auction::redelegate, commons::UREF_ADDR};
+use self::{
+    auction::redelegate,
+    commons::{sample_executables, UREF_ADDR},
+};
 
+mod account_mgmt;
 mod auction;
+mod cep18;
+mod cep78;
 mod commons;
+mod faucet;
 mod generic;
 mod native_transfer;
 pub(crate) mod sign_message;
+mod system;
 mod system_payment;
 
 // From the chainspec.
@@ -29,6 +37,14 @@ const MAX_TTL: TimeDiff = TimeDiff::from_seconds(60 * 60 * 24);
 // 1 hour.
 const TTL_HOUR: TimeDiff = TimeDiff::from_seconds(60 * 60);
 
+/// Seed of the committed test vectors, same as the `CL_TEST_SEED` of the `test-vectors` target.
+pub(crate) const VECTORS_SEED: [u8; 16] = [
+    0xc9, 0x54, 0x04, 0x6e, 0x10, 0x2b, 0xdf, 0xb7, 0xc9, 0x54, 0x04, 0x6e, 0x10, 0x2b, 0xdf, 0xb7,
+];
+
+// Chain name used by the samples unless stated otherwise.
+const DEFAULT_CHAIN_NAME: &str = "mainnet";
+
 // From the chainspec.
 const MIN_DEPS_COUNT: u8 = 0;
 const MAX_DEPS_COUNT: u8 = 10;
@@ -180,6 +196,7 @@ fn make_deploy_sample(
     ttl: TimeDiff,
     dependencies: Vec<DeployHash>,
     signing_keys: &[SecretKey],
+    chain_name: &str,
 ) -> Sample<Deploy> {
     let (main_key, secondary_keys) = signing_keys.split_at(1);
     let (payment_label, payment, payment_validity) = payment.destructure();
@@ -190,7 +207,7 @@ fn make_deploy_sample(
         ttl,
         2,
         dependencies,
-        String::from("mainnet"),
+        chain_name.to_string(),
         payment,
         session,
         &main_key[0],
@@ -266,15 +283,296 @@ fn construct_samples<R: Rng>(
             ttls.shuffle(rng);
             let ttl = ttls.first().cloned().unwrap();
 
-            let sample_deploy =
-                make_deploy_sample(session.clone(), payment.clone(), ttl, dependencies, &keys);
+            let sample_deploy = make_deploy_sample(
+                session.clone(),
+                payment.clone(),
+                ttl,
+                dependencies,
+                &keys,
+                DEFAULT_CHAIN_NAME,
+            );
             samples.push(sample_deploy);
         }
     }
     samples
 }
 
-pub(crate) fn redelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+/// Returns all the deploy samples, in the order of the test vectors.
+pub(crate) fn deploy_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut samples = undelegate_samples(rng);
+    samples.extend(delegate_samples(rng));
+    samples.extend(native_transfer_samples(rng));
+    samples.extend(redelegate_samples(rng));
+    samples.extend(generic_samples(rng));
+    samples.extend(dependencies_samples());
+    samples.extend(secp256k1_samples());
+    samples.extend(long_identifier_samples());
+    samples.extend(transfer_extra_args_samples());
+    samples.extend(cep18_samples(rng));
+    samples.extend(cep78_samples(rng));
+    samples.extend(system_samples(rng));
+    samples.extend(account_mgmt_samples(rng));
+    samples.extend(faucet_samples(rng));
+    samples.extend(foreign_signature_samples());
+    samples.extend(zero_payment_samples());
+    samples.extend(non_displayable_samples());
+    samples
+}
+
+/// Returns deploys the device can't display – calls with huge arguments and module bytes
+/// of an unknown custom payload – so that the blind signing vectors cover them.
+///
+/// The payload is larger than the module bytes of any other sample,
+/// so it's the first to be caught by a `--max-module-bytes` limit.
+fn non_displayable_samples() -> Vec<Sample<Deploy>> {
+    let huge_args = runtime_args! {
+        "payload" => vec![0xabu8; 8 * 1024],
+    };
+    let mut custom_payload = Sample::new(
+        "type_module_bytes",
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::from(vec![0xcdu8; 4 * 1024]),
+            args: RuntimeArgs::new(),
+        },
+        true,
+    );
+    custom_payload.add_label("custom_payload".to_string());
+
+    let mut sessions =
+        sample_executables("huge_args", huge_args, Some("huge_args".to_string()), true);
+    sessions.push(custom_payload);
+    sessions
+        .into_iter()
+        .map(|session| {
+            make_deploy_sample(
+                session,
+                system_payment::valid(),
+                MAX_TTL,
+                vec![],
+                &random_keys(MIN_APPROVALS_COUNT),
+                DEFAULT_CHAIN_NAME,
+            )
+            .non_displayable()
+        })
+        .collect()
+}
+
+/// Returns a native transfer paying nothing for its execution, so that the warning
+/// about it is exercised by the test vectors.
+fn zero_payment_samples() -> Vec<Sample<Deploy>> {
+    native_transfer::valid()
+        .into_iter()
+        .take(1)
+        .map(|session| {
+            make_deploy_sample(
+                session,
+                system_payment::zero(),
+                MAX_TTL,
+                vec![],
+                &random_keys(MIN_APPROVALS_COUNT),
+                DEFAULT_CHAIN_NAME,
+            )
+        })
+        .collect()
+}
+
+/// Returns samples with a fixed, non-zero number of dependencies,
+/// so that the `Dep n` elements are exercised by the test vectors.
+fn dependencies_samples() -> Vec<Sample<Deploy>> {
+    let sessions: Vec<Sample<ExecutableDeployItem>> = native_transfer::valid()
+        .into_iter()
+        .take(1)
+        .chain(delegate::valid().into_iter().take(1))
+        .collect();
+
+    let mut samples = vec![];
+    for session in sessions {
+        for deps_count in [1u8, 8] {
+            let mut sample = make_deploy_sample(
+                session.clone(),
+                system_payment::valid(),
+                MAX_TTL,
+                make_dependencies(deps_count),
+                &random_keys(MIN_APPROVALS_COUNT),
+                DEFAULT_CHAIN_NAME,
+            );
+            sample.add_label(format!("deps_{}", deps_count));
+            samples.push(sample);
+        }
+    }
+    samples
+}
+
+/// Returns samples with an approval carrying the signature of another deploy, by the same key –
+/// instead of the valid one, or next to it as a second approval of the same signer.
+/// The device doesn't check approvals, but verifying wallets must flag them.
+fn foreign_signature_samples() -> Vec<Sample<Deploy>> {
+    let session = native_transfer::valid().into_iter().next().unwrap();
+    let keys = random_keys(MIN_APPROVALS_COUNT);
+    let make_sample = |deps_count| {
+        make_deploy_sample(
+            session.clone(),
+            system_payment::valid(),
+            MAX_TTL,
+            make_dependencies(deps_count),
+            &keys,
+            DEFAULT_CHAIN_NAME,
+        )
+    };
+    let (label, deploy, validity) = make_sample(0).destructure();
+    let foreign_approval =
+        serde_json::to_value(make_sample(1).sample()).unwrap()["approvals"][0].clone();
+    [("bad_signature", true), ("duplicate_signer", false)]
+        .iter()
+        .map(|(suffix, replace)| {
+            // Approvals can't be replaced through the API, so it's done in the JSON form.
+            let mut json = serde_json::to_value(&deploy).unwrap();
+            let approvals = json["approvals"].as_array_mut().unwrap();
+            if *replace {
+                approvals.clear();
+            }
+            approvals.push(foreign_approval.clone());
+            let mut sample = Sample::new(
+                label.clone(),
+                serde_json::from_value(json).unwrap(),
+                validity,
+            );
+            sample.add_label(suffix.to_string());
+            sample
+        })
+        .collect()
+}
+
+/// Returns samples created by a secp256k1 account,
+/// so that the `02`-prefixed key formatting path is always covered.
+fn secp256k1_samples() -> Vec<Sample<Deploy>> {
+    let contract_args = runtime_args! {
+        "amount" => U512::from(100000000u64),
+    };
+    let sessions = native_transfer::valid()
+        .into_iter()
+        .take(1)
+        .chain(sample_executables(
+            "generic-txn-entrypoint",
+            contract_args,
+            None,
+            true,
+        ));
+
+    sessions
+        .map(|session| {
+            let key =
+                SecretKey::secp256k1_from_bytes([1u8; 32]).expect("successful key construction");
+            let mut sample = make_deploy_sample(
+                session,
+                system_payment::valid(),
+                MAX_TTL,
+                vec![],
+                &[key],
+                DEFAULT_CHAIN_NAME,
+            );
+            sample.add_label("account_secp256k1".to_string());
+            sample
+        })
+        .collect()
+}
+
+/// Returns samples with long identifiers (chain name, contract name and entry point),
+/// each of them spanning multiple Ledger pages.
+fn long_identifier_samples() -> Vec<Sample<Deploy>> {
+    // The protocol does not limit the length of a chain name.
+    let long_chain_name = "long-chain-name-".repeat(8);
+    // Contract names are derived from the entry point, so these are 64+ characters long too.
+    let long_entry_point = "long_entry_point_name_".repeat(3);
+    let contract_args = runtime_args! {
+        "amount" => U512::from(100000000u64),
+    };
+
+    let mut samples = vec![];
+    for session in native_transfer::valid().into_iter().take(1) {
+        let mut sample = make_deploy_sample(
+            session,
+            system_payment::valid(),
+            MAX_TTL,
+            vec![],
+            &random_keys(MIN_APPROVALS_COUNT),
+            &long_chain_name,
+        );
+        sample.add_label("long_chain_name".to_string());
+        samples.push(sample);
+    }
+    for session in sample_executables(
+        &long_entry_point,
+        contract_args,
+        Some("long_identifiers".to_string()),
+        true,
+    ) {
+        samples.push(make_deploy_sample(
+            session,
+            system_payment::valid(),
+            MAX_TTL,
+            vec![],
+            &random_keys(MIN_APPROVALS_COUNT),
+            DEFAULT_CHAIN_NAME,
+        ));
+    }
+    samples
+}
+
+/// Returns native transfers carrying extra arguments,
+/// locking in that their target, amount and ID are displayed along with the arguments' digest,
+/// and that arguments resembling the transfer's own are warned about.
+fn transfer_extra_args_samples() -> Vec<Sample<Deploy>> {
+    let mut sessions = vec![native_transfer::with_extra_args()];
+    sessions.extend(native_transfer::with_ambiguous_args());
+    sessions
+        .into_iter()
+        .map(|session| {
+            make_deploy_sample(
+                session,
+                system_payment::valid(),
+                MAX_TTL,
+                vec![],
+                &random_keys(MIN_APPROVALS_COUNT),
+                DEFAULT_CHAIN_NAME,
+            )
+        })
+        .collect()
+}
+
+fn cep18_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut samples = construct_samples(rng, cep18::valid(), vec![system_payment::valid()]);
+    samples.extend(construct_samples(
+        rng,
+        cep18::invalid(),
+        vec![system_payment::valid()],
+    ));
+    samples
+}
+
+fn cep78_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    let mut samples = construct_samples(rng, cep78::valid(), vec![system_payment::valid()]);
+    samples.extend(construct_samples(
+        rng,
+        cep78::invalid(),
+        vec![system_payment::valid()],
+    ));
+    samples
+}
+
+fn system_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    construct_samples(rng, system::valid(), vec![system_payment::valid()])
+}
+
+fn account_mgmt_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    construct_samples(rng, account_mgmt::valid(), vec![system_payment::valid()])
+}
+
+fn faucet_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+    construct_samples(rng, faucet::valid(), vec![system_payment::valid()])
+}
+
+fn redelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     let valid_samples = redelegate::valid();
     let valid_payment_samples = vec![system_payment::valid()];
 
@@ -289,7 +587,7 @@ pub(crate) fn redelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     samples
 }
 
-pub(crate) fn generic_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+fn generic_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     let valid_samples = generic::valid(rng);
     let valid_payment_samples = vec![system_payment::valid()];
 
@@ -306,7 +604,7 @@ pub(crate) fn generic_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     samples
 }
 
-pub(crate) fn native_transfer_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+fn native_transfer_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     let mut native_transfer_samples =
         construct_samples(rng, native_transfer::valid(), vec![system_payment::valid()]);
 
@@ -318,7 +616,7 @@ pub(crate) fn native_transfer_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>
     native_transfer_samples
 }
 
-pub(crate) fn delegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+fn delegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     let mut delegate_samples =
         construct_samples(rng, delegate::valid(), vec![system_payment::valid()]);
 
@@ -331,7 +629,7 @@ pub(crate) fn delegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     delegate_samples
 }
 
-pub(crate) fn undelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+fn undelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     let mut undelegate_samples =
         construct_samples(rng, undelegate::valid(), vec![system_payment::valid()]);
 
@@ -0,0 +1,63 @@
+//! Sample test vectors for the management of associated keys and action thresholds.
+//!
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `add_associated_key` | `account: AccountHash`, `weight: u8` |
+//! | `remove_associated_key` | `account: AccountHash` |
+//! | `set_action_thresholds` | `deployment_threshold: u8`, `key_management_threshold: u8` |
+//!
+//! Session code with the same arguments is recognized too.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::AccountHash, bytesrepr::Bytes, runtime_args, RuntimeArgs};
+
+use crate::{
+    sample::Sample,
+    test_data::commons::{prepend_label, sample_executables},
+};
+
+fn sample_calls() -> Vec<(&'static str, RuntimeArgs)> {
+    let account = AccountHash::new([2u8; 32]);
+    vec![
+        (
+            "add_associated_key",
+            runtime_args! {
+                "account" => account,
+                "weight" => 1u8,
+            },
+        ),
+        (
+            "remove_associated_key",
+            runtime_args! {
+                "account" => account,
+            },
+        ),
+        (
+            "set_action_thresholds",
+            runtime_args! {
+                "deployment_threshold" => 2u8,
+                "key_management_threshold" => 3u8,
+            },
+        ),
+    ]
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    sample_calls()
+        .into_iter()
+        .flat_map(|(entry_point, args)| {
+            let session = Sample::new(
+                "type_module_bytes",
+                ExecutableDeployItem::ModuleBytes {
+                    module_bytes: Bytes::from(vec![0u8; 32]),
+                    args: args.clone(),
+                },
+                true,
+            );
+            sample_executables(entry_point, args, None, true)
+                .into_iter()
+                .chain(Some(session))
+                .map(move |sample| prepend_label(sample, &format!("account_mgmt_{}", entry_point)))
+        })
+        .collect()
+}
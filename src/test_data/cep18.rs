@@ -0,0 +1,106 @@
+//! Sample test vectors for calls of CEP-18 fungible token contracts.
+//!
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `transfer` | `recipient: Key`, `amount: U256` |
+//! | `approve` | `spender: Key`, `amount: U256` |
+//! | `transfer_from` | `owner: Key`, `recipient: Key`, `amount: U256` |
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{
+    account::AccountHash, runtime_args, AsymmetricType, Key, PublicKey, RuntimeArgs, U256, U512,
+};
+
+use crate::{
+    sample::Sample,
+    test_data::commons::{prepend_label, sample_executables},
+};
+
+fn account_key(byte: u8) -> Key {
+    Key::Account(AccountHash::new([byte; 32]))
+}
+
+// Tokens can be sent to contracts too.
+fn contract_key(byte: u8) -> Key {
+    Key::Hash([byte; 32])
+}
+
+fn sample_calls() -> Vec<(&'static str, RuntimeArgs)> {
+    let mut calls = vec![];
+    for amount in [U256::one(), U256::max_value()] {
+        for recipient in [account_key(2), contract_key(5)] {
+            calls.push((
+                "transfer",
+                runtime_args! {
+                    "recipient" => recipient,
+                    "amount" => amount,
+                },
+            ));
+        }
+        calls.push((
+            "approve",
+            runtime_args! {
+                "spender" => account_key(3),
+                "amount" => amount,
+            },
+        ));
+        calls.push((
+            "transfer_from",
+            runtime_args! {
+                "owner" => account_key(4),
+                "recipient" => account_key(2),
+                "amount" => amount,
+            },
+        ));
+    }
+    calls
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    sample_calls()
+        .into_iter()
+        .flat_map(|(entry_point, args)| {
+            sample_executables(entry_point, args, None, true)
+                .into_iter()
+                .map(move |sample| prepend_label(sample, &format!("cep18_{}", entry_point)))
+        })
+        .collect()
+}
+
+/// Calls resembling the CEP-18 ones, displayed as generic contract calls.
+/// They're valid – any contract may use similar entry points.
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let amount = U256::from(100_000_000u64);
+    let recipient = PublicKey::ed25519_from_bytes([2u8; 32]).unwrap();
+    let calls = vec![
+        (
+            "extra_arg",
+            runtime_args! {
+                "recipient" => account_key(2),
+                "amount" => amount,
+                "memo" => 1u64,
+            },
+        ),
+        (
+            "u512_amount",
+            runtime_args! {
+                "recipient" => account_key(2),
+                "amount" => U512::from(100_000_000u64),
+            },
+        ),
+        (
+            "public_key_recipient",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount,
+            },
+        ),
+    ];
+    calls
+        .into_iter()
+        .flat_map(|(label, args)| {
+            sample_executables("transfer", args, Some(label.to_string()), true)
+        })
+        .map(|sample| prepend_label(sample, "cep18_transfer"))
+        .collect()
+}
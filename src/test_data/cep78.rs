@@ -0,0 +1,102 @@
+//! Sample test vectors for calls of CEP-78 NFT contracts.
+//!
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `mint` | `token_owner: Key`, `token_meta_data: String` |
+//! | `burn` | `token_id: u64` or `token_hash: String` |
+//! | `transfer` | `token_id: u64` or `token_hash: String`, `source_key: Key`, `target_key: Key` |
+//! | `approve` | `token_id: u64` or `token_hash: String`, `spender: Key` |
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+
+use crate::{
+    sample::Sample,
+    test_data::commons::{prepend_label, sample_executables},
+};
+
+const TOKEN_META_DATA: &str = r#"{"name":"Casper NFT","token_uri":"https://example.com/nft/1"}"#;
+
+fn account_key(byte: u8) -> Key {
+    Key::Account(AccountHash::new([byte; 32]))
+}
+
+// Collections identify their tokens either by number or by hash.
+fn token_identifiers() -> Vec<(&'static str, RuntimeArgs)> {
+    vec![
+        ("token_id", runtime_args! { "token_id" => u64::MAX }),
+        (
+            "token_hash",
+            runtime_args! { "token_hash" => "3f2b9a1c7e".to_string() },
+        ),
+    ]
+}
+
+fn with_args(mut args: RuntimeArgs, extra_args: RuntimeArgs) -> RuntimeArgs {
+    for arg in extra_args.named_args() {
+        args.insert_cl_value(arg.name(), arg.cl_value().clone());
+    }
+    args
+}
+
+fn sample_calls() -> Vec<(&'static str, Option<&'static str>, RuntimeArgs)> {
+    let mut calls = vec![(
+        "mint",
+        None,
+        runtime_args! {
+            "token_owner" => account_key(2),
+            "token_meta_data" => TOKEN_META_DATA.to_string(),
+        },
+    )];
+    for (label, token) in token_identifiers() {
+        calls.push(("burn", Some(label), token.clone()));
+        calls.push((
+            "transfer",
+            Some(label),
+            with_args(
+                token.clone(),
+                runtime_args! {
+                    "source_key" => account_key(2),
+                    "target_key" => account_key(3),
+                },
+            ),
+        ));
+        calls.push((
+            "approve",
+            Some(label),
+            with_args(token, runtime_args! { "spender" => account_key(4) }),
+        ));
+    }
+    calls
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    sample_calls()
+        .into_iter()
+        .flat_map(|(entry_point, label, args)| {
+            sample_executables(entry_point, args, label.map(str::to_string), true)
+                .into_iter()
+                .map(move |sample| prepend_label(sample, &format!("cep78_{}", entry_point)))
+        })
+        .collect()
+}
+
+/// Calls resembling the CEP-78 ones, displayed as generic contract calls.
+/// They're valid – any contract may use similar entry points.
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let calls = vec![
+        (
+            "both_identifiers",
+            runtime_args! {
+                "token_id" => 1u64,
+                "token_hash" => "3f2b9a1c7e".to_string(),
+            },
+        ),
+        ("u32_token_id", runtime_args! { "token_id" => 1u32 }),
+    ];
+    calls
+        .into_iter()
+        .flat_map(|(label, args)| sample_executables("burn", args, Some(label.to_string()), true))
+        .map(|sample| prepend_label(sample, "cep78_burn"))
+        .collect()
+}
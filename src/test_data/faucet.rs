@@ -0,0 +1,40 @@
+//! Sample test vectors for calls of the testnet faucet.
+//!
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `call_faucet` | `target: AccountHash`, `amount: U512`, `id: Option<u64>` |
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+use crate::{
+    sample::Sample,
+    test_data::commons::{prepend_label, sample_executables},
+};
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    let target = AccountHash::new([2u8; 32]);
+    let calls = vec![
+        (
+            "full",
+            runtime_args! {
+                "target" => target,
+                "amount" => U512::from(1_000_000_000_000u64),
+                "id" => Some(1u64),
+            },
+        ),
+        (
+            "target_only",
+            runtime_args! {
+                "target" => target,
+            },
+        ),
+    ];
+    calls
+        .into_iter()
+        .flat_map(|(label, args)| {
+            sample_executables("call_faucet", args, Some(label.to_string()), true)
+        })
+        .map(|sample| prepend_label(sample, "faucet_call_faucet"))
+        .collect()
+}
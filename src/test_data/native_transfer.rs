@@ -115,3 +115,52 @@ pub(super) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
         })
         .collect()
 }
+
+/// Returns a valid native transfer with an argument it doesn't use,
+/// so that the arguments' digest is displayed next to the transfer's details.
+pub(super) fn with_extra_args() -> Sample<ExecutableDeployItem> {
+    let args = runtime_args! {
+        "amount" => U512::from(100000000u64),
+        "target" => URef::new(UREF_ADDR, AccessRights::READ),
+        "id" => 1u64,
+        "memo" => "extra argument".to_string(),
+    };
+    Sample::new(
+        "native_transfer_extra_args",
+        ExecutableDeployItem::Transfer { args },
+        true,
+    )
+}
+
+/// Returns valid native transfers with extra arguments that could be mistaken for the transfer's own:
+/// a target differing only by case, and another amount of motes.
+pub(super) fn with_ambiguous_args() -> Vec<Sample<ExecutableDeployItem>> {
+    let case_variant_target = runtime_args! {
+        "amount" => U512::from(100000000u64),
+        "target" => URef::new(UREF_ADDR, AccessRights::READ),
+        "id" => 1u64,
+        "Target" => URef::new([1u8; 32], AccessRights::READ),
+    };
+    let second_amount = runtime_args! {
+        "amount" => U512::from(100000000u64),
+        "target" => URef::new(UREF_ADDR, AccessRights::READ),
+        "id" => 1u64,
+        "total" => U512::from(1u64),
+    };
+    vec![
+        Sample::new(
+            "native_transfer_case_variant_target",
+            ExecutableDeployItem::Transfer {
+                args: case_variant_target,
+            },
+            true,
+        ),
+        Sample::new(
+            "native_transfer_second_amount",
+            ExecutableDeployItem::Transfer {
+                args: second_amount,
+            },
+            true,
+        ),
+    ]
+}
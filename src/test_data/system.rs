@@ -0,0 +1,77 @@
+//! Sample test vectors for calls of contracts by the names of the mint and handle payment
+//! system contracts – displayed as generic calls, with a warning.
+//!
+//! | contract | entrypoint | arguments |
+//! |---------|---------|---------|
+//! | `mint` | `transfer` | `to: Option<AccountHash>`, `source: URef`, `target: URef`, `amount: U512`, `id: Option<u64>` |
+//! | `mint` | `balance` | `purse: URef` |
+//! | `handle_payment` | `set_refund_purse` | `purse: URef` |
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::AccountHash, runtime_args, AccessRights, RuntimeArgs, URef, U512};
+
+use crate::{sample::Sample, test_data::commons::UREF_ADDR};
+
+fn purse() -> URef {
+    URef::new(UREF_ADDR, AccessRights::READ_ADD_WRITE)
+}
+
+fn sample_calls() -> Vec<(&'static str, &'static str, RuntimeArgs)> {
+    vec![
+        (
+            "mint",
+            "transfer",
+            runtime_args! {
+                "to" => Some(AccountHash::new([2u8; 32])),
+                "source" => purse(),
+                "target" => URef::new([3u8; 32], AccessRights::ADD),
+                "amount" => U512::from(2_500_000_000u64),
+                "id" => Some(1u64),
+            },
+        ),
+        (
+            "mint",
+            "balance",
+            runtime_args! {
+                "purse" => purse(),
+            },
+        ),
+        (
+            "handle_payment",
+            "set_refund_purse",
+            runtime_args! {
+                "purse" => purse(),
+            },
+        ),
+    ]
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    sample_calls()
+        .into_iter()
+        .flat_map(|(name, entry_point, args)| {
+            let label = format!("system_{}_{}", name, entry_point);
+            vec![
+                Sample::new(
+                    format!("{}__type_by_name", label),
+                    ExecutableDeployItem::StoredContractByName {
+                        name: name.to_string(),
+                        entry_point: entry_point.to_string(),
+                        args: args.clone(),
+                    },
+                    true,
+                ),
+                Sample::new(
+                    format!("{}__type_versioned_by_name", label),
+                    ExecutableDeployItem::StoredVersionedContractByName {
+                        name: name.to_string(),
+                        version: None,
+                        entry_point: entry_point.to_string(),
+                        args,
+                    },
+                    true,
+                ),
+            ]
+        })
+        .collect()
+}
@@ -14,6 +14,18 @@ pub(super) fn valid() -> Sample<ExecutableDeployItem> {
     Sample::new("payment_system", payment, true)
 }
 
+/// A payment of nothing – it's displayed, but the device warns about it.
+pub(super) fn zero() -> Sample<ExecutableDeployItem> {
+    let payment = ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: runtime_args! {
+            "amount" => U512::zero()
+        },
+    };
+
+    Sample::new("payment_system_zero", payment, true)
+}
+
 pub(super) fn invalid() -> Sample<ExecutableDeployItem> {
     let payment = ExecutableDeployItem::ModuleBytes {
         module_bytes: Bytes::new(),
@@ -3,7 +3,7 @@ use casper_types::{
 };
 use itertools::Itertools;
 
-use crate::checksummed_hex;
+use crate::{checksummed_hex, error::ParseError};
 
 /// Turn JSON representation into a string.
 fn serde_value_to_str(value: &serde_json::Value) -> String {
@@ -66,12 +66,10 @@ fn drop_key_type_prefix(cl_in: String) -> String {
 /// Extracts the `parsed` field from the `CLValue`
 /// (which is a pair of type identifier and raw bytes).
 /// It should be human-readable.
-pub(crate) fn cl_value_to_string(cl_in: &CLValue) -> String {
-    match cl_in.cl_type() {
+pub(crate) fn cl_value_to_string(cl_in: &CLValue) -> Result<String, ParseError> {
+    let value = match cl_in.cl_type() {
         CLType::Key => {
-            let account: Key = FromBytes::from_bytes(cl_in.inner_bytes())
-                .expect("key account to be deserialized with FromBytes")
-                .0;
+            let account: Key = from_inner_bytes(cl_in, "key")?;
 
             match account {
                 Key::URef(uref) => checksummed_hex::encode(uref.addr()),
@@ -88,53 +86,78 @@ pub(crate) fn cl_value_to_string(cl_in: &CLValue) -> String {
                 | Key::SystemContractRegistry
                 | Key::ChainspecRegistry
                 | Key::ChecksumRegistry
-                | Key::EraSummary => parse_as_default_json(cl_in),
+                | Key::EraSummary => parse_as_default_json(cl_in)?,
             }
         }
         CLType::URef => {
-            let uref: URef = FromBytes::from_bytes(cl_in.inner_bytes())
-                .expect("uref to be deserialized with FromBytes")
-                .0;
+            let uref: URef = from_inner_bytes(cl_in, "uref")?;
             checksummed_hex::encode(uref.addr())
         }
         CLType::PublicKey => {
-            let public_key: PublicKey = FromBytes::from_bytes(cl_in.inner_bytes())
-                .expect("public key to be deserialized with FromBytes")
-                .0;
-            parse_public_key(&public_key)
+            let public_key: PublicKey = from_inner_bytes(cl_in, "public key")?;
+            parse_public_key(&public_key)?
         }
         CLType::ByteArray(length) => {
-            let (bytes, _remainder) = cl_in.inner_bytes().split_at(*length as usize);
+            let bytes = cl_in.inner_bytes().get(..*length as usize).ok_or_else(|| {
+                ParseError::InvalidValue(format!("byte array shorter than {} bytes", length))
+            })?;
 
             checksummed_hex::encode(bytes)
         }
-        _ => parse_as_default_json(cl_in),
-    }
+        _ => parse_as_default_json(cl_in)?,
+    };
+    Ok(value)
 }
 
-fn parse_as_default_json(input: &CLValue) -> String {
-    match serde_json::to_value(input) {
-        Ok(value) => {
-            let parsed = value.get("parsed").unwrap();
-            serde_value_to_str(parsed)
-        }
-        Err(err) => {
-            eprintln!("error when parsing CLValue to CLValueJson#Object, {}", err);
-            panic!("{:?}", err)
-        }
+fn from_inner_bytes<T: FromBytes>(cl_in: &CLValue, type_name: &str) -> Result<T, ParseError> {
+    FromBytes::from_bytes(cl_in.inner_bytes())
+        .map(|(value, _remainder)| value)
+        .map_err(|err| ParseError::InvalidValue(format!("malformed {}: {}", type_name, err)))
+}
+
+fn parse_as_default_json(input: &CLValue) -> Result<String, ParseError> {
+    let value = serde_json::to_value(input).map_err(|err| {
+        ParseError::InvalidValue(format!("failed to convert the CLValue to JSON: {}", err))
+    })?;
+    match value.get("parsed") {
+        Some(parsed) => Ok(serde_value_to_str(parsed)),
+        None => Err(ParseError::InvalidValue(
+            "CLValue without a parsed form".to_string(),
+        )),
     }
 }
 
 // `PublicKey`'s `String` representation includes a `PublicKey::<variant>` prefix.
 // This method drops that prefix (and the closing ')') from the `String` representation for the Ledger.
-pub(crate) fn parse_public_key(key: &PublicKey) -> String {
+pub(crate) fn parse_public_key(key: &PublicKey) -> Result<String, ParseError> {
     let key_tag = match key {
-        PublicKey::System => panic!("Did not expect system key"),
         PublicKey::Ed25519(_) => format!("0{}", ED25519_TAG),
         PublicKey::Secp256k1(_) => format!("0{}", SECP256K1_TAG),
-        _ => panic!("Should not happen - all key variants are covered at the time of writing"),
+        // The system key (and any variant added later) can't sign deploys.
+        _ => {
+            return Err(ParseError::InvalidValue(format!(
+                "unsupported public key {}",
+                key
+            )))
+        }
     };
 
     let checksummed_key = checksummed_hex::encode(Into::<Vec<u8>>::into(key));
-    format!("{}{}", key_tag, checksummed_key)
+    Ok(format!("{}{}", key_tag, checksummed_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{PublicKey, SecretKey};
+
+    use super::parse_public_key;
+
+    #[test]
+    fn secp256k1_public_key_prefix() {
+        let secret_key = SecretKey::secp256k1_from_bytes([1u8; 32]).unwrap();
+        let parsed = parse_public_key(&PublicKey::from(&secret_key)).unwrap();
+        // Algorithm tag followed by the compressed key, which itself starts with `02` or `03`.
+        assert!(parsed.starts_with("0202") || parsed.starts_with("0203"));
+        assert_eq!(parsed.len(), 2 + 2 * 33);
+    }
 }
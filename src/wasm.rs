@@ -0,0 +1,13 @@
+use casper_node::types::Deploy;
+use wasm_bindgen::prelude::*;
+
+use crate::Options;
+
+/// Returns the elements the Ledger displays for the deploy given as JSON,
+/// as a JSON array of `{ "label", "value", "expert" }` objects.
+#[wasm_bindgen(js_name = deployToElements)]
+pub fn deploy_to_elements(deploy_json: &str, expert: bool) -> Result<String, JsError> {
+    let deploy: Deploy = serde_json::from_str(deploy_json)?;
+    let elements = crate::deploy_to_elements(&deploy, &Options::default().expert(expert))?;
+    Ok(serde_json::to_string(&elements)?)
+}
@@ -0,0 +1,94 @@
+//! Stress test of the rendering shared by many threads, as done by backends
+//! rendering previews in parallel.
+
+use std::thread;
+
+use casper_deploy_generator::{deploy_to_elements, Element, Options, ParseError};
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, AccessRights, PublicKey, RuntimeArgs, SecretKey, TimeDiff,
+    Timestamp, URef, U512,
+};
+
+const THREAD_COUNT: usize = 16;
+const ITERATIONS: usize = 50;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn make_deploy(session: ExecutableDeployItem, key_seed: u8) -> Deploy {
+    let secret_key = SecretKey::ed25519_from_bytes([key_seed; 32]).unwrap();
+    Deploy::new(
+        Timestamp::from(1_620_138_035_104),
+        TimeDiff::from_seconds(60 * 60),
+        1,
+        vec![],
+        "mainnet".to_string(),
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: runtime_args! { "amount" => U512::from(1_000_000_000u64) },
+        },
+        session,
+        &secret_key,
+        None,
+    )
+}
+
+fn corpus() -> Vec<Deploy> {
+    let validator = PublicKey::from(&SecretKey::ed25519_from_bytes([3u8; 32]).unwrap());
+    (1..=8u8)
+        .flat_map(|seed| {
+            let delegator = PublicKey::from(&SecretKey::ed25519_from_bytes([seed; 32]).unwrap());
+            vec![
+                make_deploy(
+                    ExecutableDeployItem::Transfer {
+                        args: runtime_args! {
+                            "amount" => U512::from(seed),
+                            "target" => URef::new([seed; 32], AccessRights::READ),
+                            "id" => u64::from(seed),
+                        },
+                    },
+                    seed,
+                ),
+                make_deploy(
+                    ExecutableDeployItem::StoredContractByName {
+                        name: "auction".to_string(),
+                        entry_point: "delegate".to_string(),
+                        args: runtime_args! {
+                            "delegator" => delegator,
+                            "validator" => validator.clone(),
+                            "amount" => U512::from(seed),
+                        },
+                    },
+                    seed,
+                ),
+            ]
+        })
+        .collect()
+}
+
+#[test]
+fn rendering_is_consistent_across_threads() {
+    assert_send_sync::<Options>();
+    assert_send_sync::<Element>();
+    assert_send_sync::<ParseError>();
+
+    let corpus = corpus();
+    let options = Options::default().expert(true);
+    let expected: Vec<Vec<Element>> = corpus
+        .iter()
+        .map(|deploy| deploy_to_elements(deploy, &options).unwrap())
+        .collect();
+
+    thread::scope(|scope| {
+        for _ in 0..THREAD_COUNT {
+            scope.spawn(|| {
+                for _ in 0..ITERATIONS {
+                    for (deploy, expected) in corpus.iter().zip(&expected) {
+                        assert_eq!(&deploy_to_elements(deploy, &options).unwrap(), expected);
+                    }
+                }
+            });
+        }
+    });
+}
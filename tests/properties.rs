@@ -0,0 +1,194 @@
+//! Property-based checks of the rendering over arbitrary deploys:
+//! it never panics, regular mode shows a subset of the expert mode elements,
+//! and every label fits the device.
+
+use casper_deploy_generator::{deploy_to_elements, Element, Options};
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_node::types::Deploy;
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, AccessRights, CLType, CLValue, ContractHash,
+    ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, URef, U128,
+    U256, U512,
+};
+use proptest::prelude::*;
+
+// Character limit of the label row of the device.
+const LABEL_CHAR_COUNT: usize = 11;
+
+fn cl_value() -> impl Strategy<Value = CLValue> {
+    prop_oneof![
+        any::<bool>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<i32>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<i64>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<u8>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<u32>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<u64>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<u128>().prop_map(|v| CLValue::from_t(U128::from(v)).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(U256::from_little_endian(&v)).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(U512::from_little_endian(&v)).unwrap()),
+        Just(CLValue::from_t(()).unwrap()),
+        ".*".prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(Key::Account(AccountHash::new(v))).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(Key::Hash(v)).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(URef::new(v, AccessRights::READ)).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(public_key(v)).unwrap()),
+        any::<Option<u64>>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<Vec<u8>>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<[u8; 32]>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        any::<(u8, String)>().prop_map(|v| CLValue::from_t(v).unwrap()),
+        // Bytes that don't necessarily decode into the claimed type.
+        (malformed_cl_type(), any::<Vec<u8>>())
+            .prop_map(|(cl_type, bytes)| CLValue::from_components(cl_type, bytes)),
+    ]
+}
+
+fn malformed_cl_type() -> impl Strategy<Value = CLType> {
+    prop_oneof![
+        Just(CLType::Key),
+        Just(CLType::URef),
+        Just(CLType::PublicKey),
+        Just(CLType::U512),
+        Just(CLType::String),
+        Just(CLType::ByteArray(32)),
+    ]
+}
+
+fn public_key(seed: [u8; 32]) -> PublicKey {
+    PublicKey::from(&SecretKey::ed25519_from_bytes(seed).unwrap())
+}
+
+fn arg_name() -> impl Strategy<Value = String> {
+    // Names with a meaning to the parser are picked more often than random ones.
+    prop_oneof![
+        prop::sample::select(vec![
+            "amount",
+            "target",
+            "source",
+            "to",
+            "id",
+            "delegator",
+            "validator",
+            "new_validator",
+            "auction",
+        ])
+        .prop_map(String::from),
+        "[a-zA-Z_]{1,16}",
+    ]
+}
+
+fn runtime_args() -> impl Strategy<Value = RuntimeArgs> {
+    prop::collection::btree_map(arg_name(), cl_value(), 0..8).prop_map(RuntimeArgs::from)
+}
+
+fn entry_point() -> impl Strategy<Value = String> {
+    prop_oneof![
+        prop::sample::select(vec!["delegate", "undelegate", "redelegate", "transfer"])
+            .prop_map(String::from),
+        ".{0,24}",
+    ]
+}
+
+fn executable_deploy_item() -> impl Strategy<Value = ExecutableDeployItem> {
+    prop_oneof![
+        (prop::collection::vec(any::<u8>(), 0..64), runtime_args()).prop_map(
+            |(module_bytes, args)| ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::from(module_bytes),
+                args,
+            }
+        ),
+        (any::<[u8; 32]>(), entry_point(), runtime_args()).prop_map(|(hash, entry_point, args)| {
+            ExecutableDeployItem::StoredContractByHash {
+                hash: ContractHash::new(hash),
+                entry_point,
+                args,
+            }
+        }),
+        (".{0,24}", entry_point(), runtime_args()).prop_map(|(name, entry_point, args)| {
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            }
+        }),
+        (
+            any::<[u8; 32]>(),
+            any::<Option<u32>>(),
+            entry_point(),
+            runtime_args()
+        )
+            .prop_map(|(hash, version, entry_point, args)| {
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash: ContractPackageHash::new(hash),
+                    version,
+                    entry_point,
+                    args,
+                }
+            }),
+        (
+            ".{0,24}",
+            any::<Option<u32>>(),
+            entry_point(),
+            runtime_args()
+        )
+            .prop_map(|(name, version, entry_point, args)| {
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name,
+                    version,
+                    entry_point,
+                    args,
+                }
+            }),
+        runtime_args().prop_map(|args| ExecutableDeployItem::Transfer { args }),
+    ]
+}
+
+fn deploy() -> impl Strategy<Value = Deploy> {
+    (
+        executable_deploy_item(),
+        executable_deploy_item(),
+        any::<u64>(),
+        ".{0,32}",
+    )
+        .prop_map(|(payment, session, timestamp, chain_name)| {
+            let secret_key = SecretKey::ed25519_from_bytes([1u8; 32]).unwrap();
+            Deploy::new(
+                Timestamp::from(timestamp),
+                TimeDiff::from_seconds(60),
+                1,
+                vec![],
+                chain_name,
+                payment,
+                session,
+                &secret_key,
+                None,
+            )
+        })
+}
+
+fn label_value(elements: &[Element]) -> Vec<(&str, &str)> {
+    elements
+        .iter()
+        .map(|element| (element.label(), element.value()))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn rendering_never_panics(deploy in deploy()) {
+        let regular = deploy_to_elements(&deploy, &Options::default());
+        let expert = deploy_to_elements(&deploy, &Options::default().expert(true));
+        prop_assert_eq!(regular.is_ok(), expert.is_ok());
+        if let (Ok(regular), Ok(expert)) = (regular, expert) {
+            let expert_regular: Vec<Element> = expert
+                .iter()
+                .filter(|element| !element.is_expert())
+                .cloned()
+                .collect();
+            prop_assert_eq!(label_value(&regular), label_value(&expert_regular));
+            for element in &expert {
+                prop_assert!(!element.label().is_empty());
+                prop_assert!(element.label().chars().count() <= LABEL_CHAR_COUNT);
+            }
+        }
+    }
+}
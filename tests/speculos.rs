@@ -0,0 +1,127 @@
+//! End-to-end check of the test vectors against the Casper Ledger app running in Speculos:
+//! every blob is sent to the app and the screens it displays are compared with the expected
+//! regular mode pages.
+//!
+//! Start Speculos with the app first, then run:
+//!
+//! ```bash
+//! speculos --display headless --model nanos path/to/app.elf &
+//! SPECULOS_URL=http://127.0.0.1:5000 cargo test --test speculos -- --ignored
+//! ```
+//!
+//! `VECTORS` selects the test vectors file (`manual.json` by default).
+
+use std::{env, fs, thread};
+
+use casper_deploy_generator::apdu::{sign_apdus, DEFAULT_PATH};
+use serde_json::{json, Value};
+
+// Guards against navigating forever when the approval screen never shows up.
+const MAX_SCREENS: usize = 500;
+
+#[test]
+#[ignore = "requires Speculos running the Casper app"]
+fn vectors_match_speculos_screens() {
+    let speculos_url = env::var("SPECULOS_URL").unwrap_or_else(|_| "http://127.0.0.1:5000".into());
+    let vectors_path = env::var("VECTORS").unwrap_or_else(|_| "manual.json".into());
+    let vectors: Vec<Value> =
+        serde_json::from_str(&fs::read_to_string(&vectors_path).unwrap()).unwrap();
+
+    let mut mismatches = vec![];
+    for vector in &vectors {
+        let name = vector["name"].as_str().unwrap();
+        // Messages are signed with a different instruction and invalid samples are rejected
+        // before they are displayed.
+        if name.ends_with("casper_message") || !vector["valid_regular"].as_bool().unwrap() {
+            continue;
+        }
+        let blob = hex::decode(vector["blob"].as_str().unwrap()).unwrap();
+        let expected: Vec<String> = vector["output"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|page| strip_element_index(page.as_str().unwrap()))
+            .collect();
+
+        let screens = displayed_screens(&speculos_url, &blob);
+        if screens != expected {
+            mismatches.push(format!(
+                "{}:\n  expected: {:?}\n  displayed: {:?}",
+                name, expected, screens
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+/// Sends the sign request and returns the screens displayed before the approval screen,
+/// in the same format as the expected pages.
+fn displayed_screens(speculos_url: &str, blob: &[u8]) -> Vec<String> {
+    ureq::delete(&format!("{}/events", speculos_url))
+        .call()
+        .unwrap();
+
+    let mut apdus = sign_apdus(&DEFAULT_PATH, blob);
+    let last_apdu = apdus.pop().unwrap();
+    for apdu in apdus {
+        exchange(speculos_url, &apdu);
+    }
+    // The app answers the last APDU only once the deploy is approved.
+    let pending = {
+        let speculos_url = speculos_url.to_string();
+        thread::spawn(move || exchange(&speculos_url, &last_apdu))
+    };
+
+    let mut screens = vec![];
+    while screens.len() < MAX_SCREENS {
+        let texts = current_screen(speculos_url);
+        if texts
+            .first()
+            .map_or(false, |title| title.starts_with("APPROVE"))
+        {
+            press(speculos_url, "both");
+            break;
+        }
+        if let Some((title, rows)) = texts.split_first() {
+            screens.push(format!("{} : {}", title, rows.concat()));
+        }
+        press(speculos_url, "right");
+    }
+    pending.join().unwrap();
+    screens
+}
+
+fn exchange(speculos_url: &str, apdu: &[u8]) -> Value {
+    ureq::post(&format!("{}/apdu", speculos_url))
+        .send_json(json!({ "data": hex::encode(apdu) }))
+        .unwrap()
+        .into_json()
+        .unwrap()
+}
+
+fn current_screen(speculos_url: &str) -> Vec<String> {
+    let events: Value = ureq::get(&format!("{}/events?currentscreenonly=true", speculos_url))
+        .call()
+        .unwrap()
+        .into_json()
+        .unwrap();
+    events["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|event| event["text"].as_str().map(str::to_string))
+        .collect()
+}
+
+fn press(speculos_url: &str, button: &str) {
+    ureq::post(&format!("{}/button/{}", speculos_url, button))
+        .send_json(json!({ "action": "press-and-release" }))
+        .unwrap();
+}
+
+// `"3 | Fee : 1 000 motes"` is displayed as `"Fee : 1 000 motes"`.
+fn strip_element_index(page: &str) -> String {
+    page.split_once(" | ")
+        .map_or(page, |(_, page)| page)
+        .to_string()
+}